@@ -0,0 +1,224 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use telegram_bot_raw::{BaseUrl, HttpRequest, Request, ResponseType};
+
+use crate::connector::{default_connector, Connector};
+use crate::errors::Error;
+use crate::future::TelegramFuture;
+use crate::stream::UpdatesStream;
+
+/// Main type for sending requests to the Telegram bot API.
+///
+/// It is cheap to clone and can be sent to other threads, all clones share the connector.
+#[derive(Clone)]
+pub struct Api {
+    inner: Arc<ApiInner>,
+}
+
+struct ApiInner {
+    token: String,
+    base_url: BaseUrl,
+    connector: Box<dyn Connector>,
+}
+
+impl fmt::Debug for Api {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Api").field("base_url", &self.inner.base_url).finish()
+    }
+}
+
+/// Configuration for an `Api`.
+#[derive(Debug)]
+pub struct Config {
+    token: String,
+    base_url: BaseUrl,
+    connector: Option<Box<dyn Connector>>,
+}
+
+impl Config {
+    /// Set connector type for an `Api`.
+    pub fn connector(self, connector: Box<dyn Connector>) -> Config {
+        Config {
+            connector: Some(connector),
+            ..self
+        }
+    }
+
+    /// Send requests to a self-hosted Bot API server or a mock server located at `url`.
+    pub fn base_url<U: Into<BaseUrl>>(self, url: U) -> Config {
+        let url = url.into();
+        Config {
+            base_url: if self.base_url.is_test_environment() { url.test_environment() } else { url },
+            ..self
+        }
+    }
+
+    /// Use the Telegram test environment, requires a token issued in the test environment.
+    pub fn test_environment(self) -> Config {
+        Config {
+            base_url: self.base_url.clone().test_environment(),
+            ..self
+        }
+    }
+
+    /// Create new `Api` instance.
+    pub fn build(self) -> Result<Api, Error> {
+        let connector = match self.connector {
+            Some(connector) => connector,
+            None => default_connector()?,
+        };
+
+        Ok(Api {
+            inner: Arc::new(ApiInner {
+                token: self.token,
+                base_url: self.base_url,
+                connector: connector,
+            }),
+        })
+    }
+}
+
+impl Api {
+    /// Create a new `Api` instance with the default connector.
+    pub fn new<T: AsRef<str>>(token: T) -> Result<Api, Error> {
+        Api::configure(token).build()
+    }
+
+    /// Start construction of the `Api` instance.
+    pub fn configure<T: AsRef<str>>(token: T) -> Config {
+        Config {
+            token: token.as_ref().to_string(),
+            base_url: Default::default(),
+            connector: None,
+        }
+    }
+
+    /// Create a stream which produces updates from the Telegram server.
+    pub fn stream(&self) -> UpdatesStream {
+        UpdatesStream::new(self.clone())
+    }
+
+    /// Send a request to the Telegram server and do not wait for a response.
+    ///
+    /// Must be called from within a `tokio` runtime.
+    pub fn spawn<Req: Request>(&self, request: Req)
+        where Req::Response: 'static, <Req::Response as ResponseType>::Type: Send
+    {
+        tokio::spawn(self.send(request));
+    }
+
+    /// Send a request to the Telegram server and wait for a response.
+    ///
+    /// The request is serialized immediately, so the returned future doesn't borrow it
+    /// and can be spawned on a multi-threaded runtime.
+    pub fn send<Req: Request>(&self, request: Req) -> TelegramFuture<<Req::Response as ResponseType>::Type>
+        where Req::Response: 'static
+    {
+        let request = request.serialize();
+        self.send_http::<Req::Response>(request)
+    }
+
+    /// Send a request to the Telegram server and wait for a response, timing out after `duration`.
+    /// Resolves to `None` if timeout fired.
+    pub fn send_timeout<Req: Request>(&self, request: Req, duration: Duration)
+        -> TelegramFuture<Option<<Req::Response as ResponseType>::Type>>
+        where Req::Response: 'static
+    {
+        let future = self.send(request);
+        Box::pin(async move {
+            match tokio::time::timeout(duration, future).await {
+                Ok(response) => response.map(Some),
+                Err(_) => Ok(None),
+            }
+        })
+    }
+
+    fn send_http<Resp>(&self, request: Result<HttpRequest, telegram_bot_raw::Error>) -> TelegramFuture<Resp::Type>
+        where Resp: ResponseType + 'static
+    {
+        let api = self.clone();
+        Box::pin(async move {
+            let request = request?;
+            let response = api.inner.connector.request(&api.inner.base_url, &api.inner.token, request).await?;
+            Ok(Resp::deserialize(response)?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use futures::StreamExt;
+    use serde_json::{json, Value};
+
+    use telegram_bot_raw::{Body, GetMe, HttpResponse, Integer};
+
+    use super::*;
+
+    /// Replies to requests with queued results in order, records the bodies of requests.
+    #[derive(Debug, Default)]
+    struct ScriptedConnector {
+        results: Mutex<VecDeque<Value>>,
+        requests: Arc<Mutex<Vec<Value>>>,
+    }
+
+    impl Connector for ScriptedConnector {
+        fn request(&self, _base_url: &BaseUrl, _token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+            if let Body::Json(ref body) = req.body {
+                self.requests.lock().unwrap().push(serde_json::from_slice(body).unwrap());
+            }
+            let result = self.results.lock().unwrap().pop_front();
+            Box::pin(async move {
+                let body = match result {
+                    Some(result) => json!({"ok": true, "result": result}),
+                    None => json!({"ok": false, "description": "no response"}),
+                };
+                Ok(HttpResponse { body: Some(serde_json::to_vec(&body).unwrap()) })
+            })
+        }
+    }
+
+    fn api(results: Vec<Value>) -> (Api, Arc<Mutex<Vec<Value>>>) {
+        let connector = ScriptedConnector {
+            results: Mutex::new(results.into_iter().collect()),
+            requests: Default::default(),
+        };
+        let requests = connector.requests.clone();
+        (Api::configure("token").connector(Box::new(connector)).build().unwrap(), requests)
+    }
+
+    fn update(id: Integer) -> Value {
+        json!({
+            "update_id": id,
+            "message": {
+                "message_id": id, "date": 0, "text": "text",
+                "from": {"id": 1, "first_name": "User"},
+                "chat": {"id": 1, "type": "private", "first_name": "User"}
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn send_from_other_task() {
+        let (api, _) = api(vec![json!({"id": 1, "first_name": "Bot", "username": "bot"})]);
+
+        let me = tokio::spawn(api.send(GetMe)).await.unwrap().unwrap();
+        assert_eq!(me.first_name, "Bot");
+        assert!(api.send(GetMe).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stream_updates() {
+        let (api, requests) = api(vec![json!([update(1), update(2)]), json!([update(3)])]);
+
+        let ids: Vec<_> = api.stream().take(3).map(|update| update.unwrap().id).collect().await;
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        let offsets: Vec<_> = requests.lock().unwrap().iter().map(|request| request["offset"].clone()).collect();
+        assert_eq!(offsets, vec![json!(1), json!(3)]);
+    }
+}
@@ -0,0 +1,98 @@
+//! Connectors sending requests to the Bot API server.
+
+use std::fmt::Debug;
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::multipart::{Form, Part};
+
+use telegram_bot_raw::{BaseUrl, Body, HttpRequest, HttpResponse, Method, Multipart, MultipartValue};
+
+use crate::errors::Error;
+use crate::future::TelegramFuture;
+
+/// Connector provides basic IO with Telegram Bot API server.
+/// It is shared by all clones of an `Api`, so it must be `Send` and `Sync`.
+pub trait Connector: Debug + Send + Sync {
+    /// Send a request to the Bot API server located at `base_url`.
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse>;
+}
+
+/// This connector uses `reqwest` backend, proxies are configured with
+/// `HTTPS_PROXY` and `NO_PROXY` environment variables.
+#[derive(Debug, Clone)]
+pub struct ReqwestConnector {
+    client: reqwest::Client,
+}
+
+impl ReqwestConnector {
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestConnector {
+            client: client,
+        }
+    }
+}
+
+impl Connector for ReqwestConnector {
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let client = self.client.clone();
+        let url = req.url.url(base_url, token);
+
+        Box::pin(async move {
+            let method = match req.method {
+                Method::Get => reqwest::Method::GET,
+                Method::Post => reqwest::Method::POST,
+            };
+            let mut request = client.request(method, &url);
+
+            match req.body {
+                Body::Empty => (),
+                Body::Json(body) => request = request.header(CONTENT_TYPE, "application/json").body(body),
+                Body::Multipart(parts) => request = request.multipart(multipart_form(parts).await?),
+                body => panic!("Unknown body type {:?}", body),
+            }
+
+            let body = request.send().await?.bytes().await?;
+            Ok(HttpResponse {
+                body: Some(body.to_vec()),
+            })
+        })
+    }
+}
+
+/// Files of `Path` parts are streamed from the file system while the request is sent.
+async fn multipart_form(parts: Multipart) -> Result<Form, Error> {
+    let mut form = Form::new();
+
+    for (name, value) in parts {
+        let part = match value {
+            MultipartValue::Text(text) => Part::text(text),
+            MultipartValue::Path { path, file_name, mime } => {
+                let part = Part::file(path).await?;
+                let part = match file_name {
+                    Some(file_name) => part.file_name(file_name),
+                    None => part,
+                };
+                with_mime(part, mime)?
+            }
+            MultipartValue::Data { file_name, mime, data } => {
+                with_mime(Part::bytes(data).file_name(file_name), mime)?
+            }
+        };
+        form = form.part(name, part);
+    }
+
+    Ok(form)
+}
+
+fn with_mime(part: Part, mime: Option<String>) -> Result<Part, Error> {
+    match mime {
+        Some(mime) => Ok(part.mime_str(&mime)?),
+        None => Ok(part),
+    }
+}
+
+/// Returns default `reqwest` connector.
+pub fn default_connector() -> Result<Box<dyn Connector>, Error> {
+    let client = reqwest::Client::builder().build()?;
+    Ok(Box::new(ReqwestConnector::new(client)))
+}
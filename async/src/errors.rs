@@ -0,0 +1,12 @@
+use telegram_bot_raw;
+
+error_chain! {
+    foreign_links {
+        Reqwest(::reqwest::Error);
+        Io(::std::io::Error);
+    }
+
+    links {
+        Raw(telegram_bot_raw::Error, telegram_bot_raw::ErrorKind);
+    }
+}
@@ -0,0 +1,7 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::errors::Error;
+
+/// Future resolving into a Telegram API response, it can be sent to other threads.
+pub type TelegramFuture<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
@@ -0,0 +1,45 @@
+//! Asynchronous client for the Telegram Bot API, to be used with `async`/`await`
+//! on the `tokio` 1.x runtime. Requests and types are shared with `telegram-bot`,
+//! see [readme](https://github.com/telegram-rs/telegram-bot) for details.
+//!
+//! `Api` is `Send` and `Sync`, its clones can be moved to other threads and tasks.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use telegram_bot_async::{Api, CanReplySendMessage, Error, MessageKind, UpdateKind};
+//!
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() -> Result<(), Error> {
+//! # let telegram_token = "token";
+//!     let api = Api::new(telegram_token)?;
+//!
+//!     let mut stream = api.stream();
+//!     while let Some(update) = stream.next().await {
+//!         if let UpdateKind::Message(message) = update?.kind {
+//!             if let MessageKind::Text { ref data, .. } = message.kind {
+//!                 api.send(message.text_reply(format!("You wrote '{}'", data))).await?;
+//!             }
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+#[macro_use]
+extern crate error_chain;
+
+mod api;
+mod errors;
+mod future;
+mod stream;
+
+pub mod connector;
+
+pub use crate::api::{Api, Config};
+pub use crate::connector::{Connector, ReqwestConnector, default_connector};
+pub use crate::errors::{Error, ErrorKind};
+pub use crate::future::TelegramFuture;
+pub use crate::stream::UpdatesStream;
+pub use telegram_bot_raw::*;
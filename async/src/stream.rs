@@ -0,0 +1,133 @@
+use std::cmp::max;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+
+use telegram_bot_raw::{AllowedUpdate, GetUpdates, Integer, Update};
+
+use crate::api::Api;
+use crate::errors::Error;
+use crate::future::TelegramFuture;
+
+const TELEGRAM_LONG_POLL_TIMEOUT_SECONDS: u64 = 5;
+const TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS: u64 = 500;
+
+/// This type represents stream of Telegram API updates and uses
+/// long polling method under the hood.
+///
+/// Errors are returned as items and followed by the next request after `error_delay`.
+#[must_use = "streams do nothing unless polled"]
+pub struct UpdatesStream {
+    api: Api,
+    last_update: Integer,
+    buffer: VecDeque<Update>,
+    current_request: Option<TelegramFuture<Option<Vec<Update>>>>,
+    timeout: Duration,
+    error_delay: Duration,
+    allowed_updates: Vec<AllowedUpdate>,
+    limit: Option<Integer>,
+}
+
+impl UpdatesStream {
+    pub(crate) fn new(api: Api) -> Self {
+        UpdatesStream {
+            api: api,
+            last_update: 0,
+            buffer: VecDeque::new(),
+            current_request: None,
+            timeout: Duration::from_secs(TELEGRAM_LONG_POLL_TIMEOUT_SECONDS),
+            error_delay: Duration::from_millis(TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS),
+            allowed_updates: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Set timeout for long polling requests, this corresponds with `timeout` field
+    /// in [getUpdates](https://core.telegram.org/bots/api#getupdates) method,
+    /// also this stream sets an additional request timeout for `timeout + 1 second`
+    /// in case of invalid Telegram API server behaviour.
+    ///
+    /// Default timeout is 5 seconds.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set a delay between erroneous request and next request.
+    ///
+    /// Default delay is 500 ms.
+    pub fn error_delay(&mut self, delay: Duration) -> &mut Self {
+        self.error_delay = delay;
+        self
+    }
+
+    /// Receive only updates of the given kinds.
+    pub fn allowed_updates(&mut self, updates: &[AllowedUpdate]) -> &mut Self {
+        self.allowed_updates = updates.to_vec();
+        self
+    }
+
+    /// Limit the number of updates received with one request, 1-100.
+    pub fn limit(&mut self, limit: Integer) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn request(&self, delay: Option<Duration>) -> TelegramFuture<Option<Vec<Update>>> {
+        let mut request = GetUpdates::new();
+        request.offset(self.last_update + 1)
+            .timeout(self.timeout.as_secs() as Integer)
+            .allowed_updates(&self.allowed_updates);
+        if let Some(limit) = self.limit {
+            request.limit(limit);
+        }
+
+        let updates = self.api.send_timeout(request, self.timeout + Duration::from_secs(1));
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            updates.await
+        })
+    }
+}
+
+impl Stream for UpdatesStream {
+    type Item = Result<Update, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+
+        loop {
+            if let Some(update) = stream.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(update)))
+            }
+
+            let mut request = match stream.current_request.take() {
+                Some(request) => request,
+                None => stream.request(None),
+            };
+
+            match request.as_mut().poll(cx) {
+                Poll::Pending => {
+                    stream.current_request = Some(request);
+                    return Poll::Pending
+                }
+                Poll::Ready(Ok(None)) => (),
+                Poll::Ready(Ok(Some(updates))) => {
+                    for update in updates {
+                        stream.last_update = max(update.id, stream.last_update);
+                        stream.buffer.push_back(update)
+                    }
+                }
+                Poll::Ready(Err(err)) => {
+                    stream.current_request = Some(stream.request(Some(stream.error_delay)));
+                    return Poll::Ready(Some(Err(err)))
+                }
+            }
+        }
+    }
+}
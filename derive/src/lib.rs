@@ -0,0 +1,170 @@
+//! Derive macros for `telegram-bot`, use them through the `derive` feature of `telegram-bot`.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Lit, Meta, NestedMeta, Variant};
+
+/// Implements `telegram_bot::BotCommands` for an enum, see its documentation.
+#[proc_macro_derive(BotCommands, attributes(command))]
+pub fn derive_bot_commands(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match bot_commands(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Command described by a variant of the enum.
+struct CommandVariant {
+    name: String,
+    description: String,
+    arguments: Vec<String>,
+    parse: TokenStream2,
+}
+
+fn bot_commands(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => return Err(Error::new_spanned(input, "BotCommands can only be derived for enums")),
+    };
+
+    let ident = &input.ident;
+    let commands = data.variants.iter()
+        .map(|variant| command_variant(ident, variant))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let names: Vec<_> = commands.iter().map(|command| command.name.as_str()).collect();
+    let descriptions: Vec<_> = commands.iter().map(|command| command.description.as_str()).collect();
+    let parse: Vec<_> = commands.iter().map(|command| &command.parse).collect();
+    let help = commands.iter().map(help_line).collect::<Vec<_>>().join("\n");
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::telegram_bot::BotCommands for #ident #ty_generics #where_clause {
+            fn from_command(__command: &::telegram_bot::Command)
+                -> ::std::result::Result<Self, ::telegram_bot::CommandError>
+            {
+                match __command.name.as_str() {
+                    #( #names => { #parse } )*
+                    _ => ::std::result::Result::Err(
+                        ::telegram_bot::CommandError::UnknownCommand(__command.name.clone())
+                    ),
+                }
+            }
+
+            fn bot_commands() -> ::std::vec::Vec<::telegram_bot::BotCommand> {
+                vec![#( ::telegram_bot::BotCommand::new(#names, #descriptions) ),*]
+            }
+
+            fn help() -> ::std::string::String {
+                ::std::string::String::from(#help)
+            }
+        }
+    })
+}
+
+fn command_variant(ident: &Ident, variant: &Variant) -> Result<CommandVariant, Error> {
+    let mut name = snake_case(&variant.ident.to_string());
+    let mut description = None;
+    let mut docs = Vec::new();
+
+    for attr in &variant.attrs {
+        if attr.path.is_ident("doc") {
+            if let Meta::NameValue(meta) = attr.parse_meta()? {
+                if let Lit::Str(doc) = meta.lit {
+                    docs.push(doc.value().trim().to_string());
+                }
+            }
+        } else if attr.path.is_ident("command") {
+            for (key, value) in command_attributes(attr)? {
+                match key.as_str() {
+                    "rename" => name = value,
+                    "description" => description = Some(value),
+                    _ => return Err(Error::new_spanned(attr, format!("unknown command attribute `{}`", key))),
+                }
+            }
+        }
+    }
+
+    let variant_ident = &variant.ident;
+    let (arguments, constructor) = match variant.fields {
+        Fields::Unit => (Vec::new(), quote!(#ident::#variant_ident)),
+        Fields::Named(ref fields) => {
+            let fields: Vec<_> = fields.named.iter().filter_map(|field| field.ident.as_ref()).collect();
+            let values = argument_idents(fields.len());
+            let arguments = fields.iter().map(|field| field.to_string()).collect();
+            (arguments, quote!(#ident::#variant_ident { #( #fields: #values ),* }))
+        }
+        Fields::Unnamed(ref fields) => {
+            let values = argument_idents(fields.unnamed.len());
+            let arguments = (1..fields.unnamed.len() + 1).map(|index| format!("arg{}", index)).collect();
+            (arguments, quote!(#ident::#variant_ident( #( #values ),* )))
+        }
+    };
+
+    let values = argument_idents(arguments.len());
+    let count = arguments.len();
+    let parse = quote! {
+        let mut __args = __command.args.iter();
+        #( let #values = ::telegram_bot::commands::parse_argument(__command, #arguments, __args.next())?; )*
+        ::telegram_bot::commands::ensure_arguments_count(__command, #count)?;
+        ::std::result::Result::Ok(#constructor)
+    };
+
+    Ok(CommandVariant {
+        name: name,
+        description: description.unwrap_or_else(|| docs.join(" ").trim().to_string()),
+        arguments: arguments,
+        parse: parse,
+    })
+}
+
+/// Pairs of `#[command(key = "value", ...)]`.
+fn command_attributes(attr: &Attribute) -> Result<Vec<(String, String)>, Error> {
+    let list = match attr.parse_meta()? {
+        Meta::List(list) => list,
+        meta => return Err(Error::new_spanned(meta, "expected #[command(key = \"value\")]")),
+    };
+
+    list.nested.iter().map(|nested| match *nested {
+        NestedMeta::Meta(Meta::NameValue(ref meta)) => match (meta.path.get_ident(), &meta.lit) {
+            (Some(key), &Lit::Str(ref value)) => Ok((key.to_string(), value.value())),
+            _ => Err(Error::new_spanned(meta, "expected key = \"value\"")),
+        },
+        ref nested => Err(Error::new_spanned(nested, "expected key = \"value\"")),
+    }).collect()
+}
+
+fn argument_idents(count: usize) -> Vec<Ident> {
+    (0..count).map(|index| Ident::new(&format!("__arg{}", index), Span::call_site())).collect()
+}
+
+fn help_line(command: &CommandVariant) -> String {
+    let mut line = format!("/{}", command.name);
+    for argument in &command.arguments {
+        line.push_str(&format!(" <{}>", argument));
+    }
+    if !command.description.is_empty() {
+        line.push_str(" - ");
+        line.push_str(&command.description);
+    }
+    line
+}
+
+/// `BanUser` becomes `ban_user`.
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (index, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() && index > 0 {
+            result.push('_');
+        }
+        result.extend(ch.to_lowercase());
+    }
+    result
+}
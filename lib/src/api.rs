@@ -1,17 +1,34 @@
 use std::borrow::Borrow;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Write;
+#[cfg(feature = "webhook")]
+use std::net::SocketAddr;
+use std::path::Path;
+use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::Duration;
 
-use futures::{Future};
-use futures::future::{result};
+use futures::{Future, Stream};
+use futures::future::{result, ok, loop_fn, Either, Loop};
+use futures::stream;
 use tokio_core::reactor::{Handle, Timeout};
 
-use telegram_bot_raw::{Request, ResponseType};
+use telegram_bot_raw::{Request, ResponseType, File, HttpRequest, HttpResponse, RequestUrl, Method, Body};
+use telegram_bot_raw::BaseUrl;
+use telegram_bot_raw::ToChatRef;
 
-use connector::{Connector, default_connector};
-use errors::Error;
+use connector::{Connector, Layer, Proxy, proxy_connector};
+use download::{FileStream, NewFileStream, MAX_DOWNLOAD_SIZE};
+use errors::{Error, ErrorKind};
 use future::{TelegramFuture, NewTelegramFuture};
+use migration::{ChatMigration, MigrationHook, migrated_to};
+use rate_limit::{RateLimiter, RateLimitMetrics, RateLimits, Reservation, SystemClock};
+use retry::RetryPolicy;
 use stream::{NewUpdatesStream, UpdatesStream};
+use sync_api::{NewSyncApi, SyncApi};
+#[cfg(feature = "webhook")]
+use webhook::WebhookServer;
 
 /// Main type for sending requests to the Telegram bot API.
 #[derive(Clone)]
@@ -21,8 +38,12 @@ pub struct Api {
 
 struct ApiInner {
     token: String,
+    base_url: BaseUrl,
     connector: Box<Connector>,
     handle: Handle,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<Rc<RefCell<RateLimiter>>>,
+    migration_hook: Option<MigrationHook>,
 }
 
 #[derive(Debug)]
@@ -42,38 +63,254 @@ impl ConnectorConfig {
         ConnectorConfig::Specified(connector)
     }
 
-    pub fn take(self, handle: &Handle) -> Result<Box<Connector>, Error> {
+    pub fn take(self, handle: &Handle, proxy: ProxyConfig) -> Result<Box<Connector>, Error> {
         match self {
-            ConnectorConfig::Default => default_connector(&handle),
+            ConnectorConfig::Default => proxy_connector(&handle, proxy.take()),
             ConnectorConfig::Specified(connector) => Ok(connector)
         }
     }
 }
 
+#[derive(Debug)]
+pub enum ProxyConfig {
+    Env,
+    Disabled,
+    Specified(Proxy),
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        ProxyConfig::Env
+    }
+}
+
+impl ProxyConfig {
+    pub fn take(self) -> Option<Proxy> {
+        match self {
+            ProxyConfig::Env => Proxy::from_env(),
+            ProxyConfig::Disabled => None,
+            ProxyConfig::Specified(proxy) => Some(proxy),
+        }
+    }
+}
+
 /// Configuration for an `Api`.
 #[derive(Debug)]
 pub struct Config {
     token: String,
+    base_url: BaseUrl,
     connector: ConnectorConfig,
+    proxy: ProxyConfig,
+    layers: Vec<Box<Layer>>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limits: Option<RateLimits>,
+    migration_hook: Option<MigrationHook>,
 }
 
 impl Config {
     /// Set connector type for an `Api`.
     pub fn connector(self, connector: Box<Connector>) -> Config {
         Config {
-            token: self.token,
             connector: ConnectorConfig::new(connector),
+            ..self
+        }
+    }
+
+    /// Wrap the connector with the `layer`, layers added later wrap
+    /// the ones added earlier and see requests first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::{Api, LoggingLayer, MetricsLayer};
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token)
+    ///     .layer(LoggingLayer::new())
+    ///     .layer(MetricsLayer::new())
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn layer<L: Layer + 'static>(mut self, layer: L) -> Config {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Send requests to a self-hosted Bot API server or a mock server located at `url`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::Api;
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token)
+    ///     .base_url("http://localhost:8081/")
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn base_url<U: Into<BaseUrl>>(self, url: U) -> Config {
+        let url = url.into();
+        Config {
+            base_url: if self.base_url.is_test_environment() { url.test_environment() } else { url },
+            ..self
+        }
+    }
+
+    /// Send requests through the `proxy` instead of the one configured with
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables.
+    /// Ignored if a connector is set with `connector`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::{Api, Proxy};
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token)
+    ///     .proxy(Proxy::socks5("localhost", 1080).credentials("user", "password"))
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn proxy(self, proxy: Proxy) -> Config {
+        Config {
+            proxy: ProxyConfig::Specified(proxy),
+            ..self
+        }
+    }
+
+    /// Send requests directly, ignoring proxy environment variables.
+    pub fn without_proxy(self) -> Config {
+        Config {
+            proxy: ProxyConfig::Disabled,
+            ..self
+        }
+    }
+
+    /// Use the Telegram test environment, requires a token issued in the test environment.
+    pub fn test_environment(self) -> Config {
+        Config {
+            base_url: self.base_url.clone().test_environment(),
+            ..self
+        }
+    }
+
+    /// Re-send requests rejected by the flood control according to `policy`.
+    /// By default such requests fail with `TelegramError`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::{Api, RetryPolicy};
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let policy = RetryPolicy::new(3).on_throttle(|throttling| {
+    ///     println!("Waiting {:?} before retry", throttling.retry_after)
+    /// });
+    /// let api = Api::configure(telegram_token)
+    ///     .retry_policy(policy)
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn retry_policy(self, policy: RetryPolicy) -> Config {
+        Config {
+            retry_policy: Some(policy),
+            ..self
+        }
+    }
+
+    /// Delay requests to not exceed `limits`. By default requests are sent immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::{Api, Rate, RateLimits};
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token)
+    ///     .rate_limits(RateLimits::new().global(Rate::per_second(20)))
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn rate_limits(self, limits: RateLimits) -> Config {
+        Config {
+            rate_limits: Some(limits),
+            ..self
+        }
+    }
+
+    /// Follow group to supergroup migrations: when a request fails because the group
+    /// was upgraded to a supergroup, send it once again to the supergroup
+    /// and call `hook`, so the stored chat identifiers can be updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use telegram_bot::Api;
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn main() {
+    /// let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token)
+    ///     .follow_chat_migrations(|migration| {
+    ///         println!("{:?} is now {:?}", migration.from, migration.to)
+    ///     })
+    ///     .build(core.handle()).unwrap();
+    /// # }
+    /// ```
+    pub fn follow_chat_migrations<F>(self, hook: F) -> Config
+        where F: Fn(&ChatMigration) + 'static
+    {
+        Config {
+            migration_hook: Some(MigrationHook::new(hook)),
+            ..self
         }
     }
 
     /// Create new `Api` instance.
     pub fn build<H: Borrow<Handle>>(self, handle: H) -> Result<Api, Error> {
         let handle = handle.borrow().clone();
+        let connector = self.connector.take(&handle, self.proxy)?;
+        let connector = self.layers.iter().fold(connector, |connector, layer| layer.layer(connector));
         Ok(Api {
             inner: Rc::new(ApiInner {
                 token: self.token,
-                connector: self.connector.take(&handle)?,
+                base_url: self.base_url,
+                connector: connector,
                 handle: handle,
+                retry_policy: self.retry_policy,
+                rate_limiter: self.rate_limits.map(|limits| {
+                    Rc::new(RefCell::new(RateLimiter::new(limits, Box::new(SystemClock))))
+                }),
+                migration_hook: self.migration_hook,
             }),
         })
     }
@@ -124,7 +361,13 @@ impl Api {
     pub fn configure<T: AsRef<str>>(token: T) -> Config {
         Config {
             token: token.as_ref().to_string(),
+            base_url: Default::default(),
             connector: Default::default(),
+            proxy: Default::default(),
+            layers: Vec::new(),
+            retry_policy: None,
+            rate_limits: None,
+            migration_hook: None,
         }
     }
 
@@ -153,6 +396,37 @@ impl Api {
         UpdatesStream::new(self.clone(), self.inner.handle.clone())
     }
 
+    /// Start an HTTP server which receives updates from the Telegram server via webhook.
+    /// Updates posted to any path other than `path` are rejected.
+    ///
+    /// The webhook itself should be registered with the `setWebhook` method,
+    /// usually behind a reverse proxy which terminates TLS.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate futures;
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// # use telegram_bot::Api;
+    /// # use tokio_core::reactor::Core;
+    /// # fn main() {
+    /// # let core = Core::new().unwrap();
+    /// # let api: Api = Api::configure("token").build(core.handle()).unwrap();
+    /// use futures::Stream;
+    ///
+    /// let addr = "127.0.0.1:0".parse().unwrap();
+    /// let future = api.webhook(&addr, "/secret").unwrap().for_each(|update| {
+    ///     println!("{:?}", update);
+    ///     Ok(())
+    /// });
+    /// # }
+    /// ```
+    #[cfg(feature = "webhook")]
+    pub fn webhook<P: AsRef<str>>(&self, addr: &SocketAddr, path: P) -> Result<WebhookServer, Error> {
+        WebhookServer::bind(addr, path, &self.inner.handle)
+    }
+
     /// Send a request to the Telegram server and do not wait for a response.
     ///
     /// # Examples
@@ -250,15 +524,227 @@ impl Api {
         let request = result(request);
 
         let api = self.clone();
-        let response = request.and_then(move |request| {
-            let ref token = api.inner.token;
-            api.inner.connector.request(token, request)
+        let future = request.and_then(move |request| {
+            loop_fn(SendState::new(request), move |state| {
+                let api = api.clone();
+                let response = api.send_http(state.request.clone());
+
+                response.and_then(move |response| {
+                    Req::Response::deserialize(response).map_err(From::from)
+                }).then(move |response| match response {
+                    Ok(response) => Either::A(result(Ok(Loop::Break(response)))),
+                    Err(err) => Either::B(api.recover(state, err).map(Loop::Continue)),
+                })
+            })
         });
 
-        let future = response.and_then(move |response| {
-            Req::Response::deserialize(response).map_err(From::from)
+        TelegramFuture::new(Box::new(future))
+    }
+
+    /// Create a handle which can be sent to other threads, see `SyncApi`.
+    /// Requests are sent while the `Core` of the `Api` is running.
+    pub fn sync_api(&self) -> SyncApi {
+        SyncApi::new(self, &self.inner.handle)
+    }
+
+    /// Current state of the rate limiter queues, if rate limits are configured.
+    pub fn rate_limit_metrics(&self) -> Option<RateLimitMetrics> {
+        self.inner.rate_limiter.as_ref().map(|limiter| RefCell::borrow(limiter).metrics())
+    }
+
+    fn rate_limit(&self, request: &HttpRequest) -> TelegramFuture<()> {
+        let limiter = match self.inner.rate_limiter {
+            Some(ref limiter) => limiter,
+            None => return TelegramFuture::new(Box::new(ok(()))),
+        };
+        let reservation = match request.chat_ref() {
+            Some(chat) => Reservation::new(limiter, chat),
+            None => return TelegramFuture::new(Box::new(ok(()))),
+        };
+
+        let timeout = result(Timeout::new(reservation.delay(), &self.inner.handle)).flatten();
+        TelegramFuture::new(Box::new(timeout.map_err(From::from).map(move |()| drop(reservation))))
+    }
+
+    fn send_http(&self, request: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let api = self.clone();
+        let future = self.rate_limit(&request).and_then(move |()| {
+            api.inner.connector.request(&api.inner.base_url, &api.inner.token, request)
         });
 
         TelegramFuture::new(Box::new(future))
     }
+
+    /// Prepare the failed request to be sent again, if possible.
+    fn recover(&self, mut state: SendState, error: Error) -> TelegramFuture<SendState> {
+        if let Some(migration) = self.migration(&mut state, &error) {
+            if let Some(ref hook) = self.inner.migration_hook {
+                hook.call(&migration)
+            }
+            return TelegramFuture::new(Box::new(ok(state)))
+        }
+
+        let delay = match self.inner.retry_policy {
+            Some(ref policy) => policy.throttle(&state.request.url, &error, state.attempt),
+            None => None,
+        };
+
+        match delay {
+            Some(delay) => {
+                state.attempt += 1;
+                let timeout = result(Timeout::new(delay, &self.inner.handle)).flatten();
+                TelegramFuture::new(Box::new(timeout.map_err(From::from).map(move |()| state)))
+            }
+            None => TelegramFuture::new(Box::new(result(Err(error)))),
+        }
+    }
+
+    /// Rewrite the request to a migrated group, only once per request.
+    fn migration(&self, state: &mut SendState, error: &Error) -> Option<ChatMigration> {
+        if self.inner.migration_hook.is_none() || state.migrated {
+            return None
+        }
+
+        let to = migrated_to(error)?;
+        let from = state.request.chat_ref()?;
+        if !state.request.set_chat_ref(&to.to_chat_ref()) {
+            return None
+        }
+
+        state.migrated = true;
+        Some(ChatMigration {
+            from: from,
+            to: to,
+        })
+    }
+
+    /// Url of a file returned by the `getFile` method on the configured Bot API server.
+    ///
+    /// The url contains the bot token, so it shouldn't be shared.
+    pub fn file_url(&self, file: &File) -> Option<String> {
+        file.get_url_with(&self.inner.base_url, &self.inner.token)
+    }
+
+    /// Download a file returned by the `getFile` method.
+    ///
+    /// Files larger than 20MB can't be downloaded by bots from the official server
+    /// and result in `ErrorKind::FileTooLarge` error. Self-hosted servers configured
+    /// with `Config::base_url` have no such limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate futures;
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// # use futures::Future;
+    /// # use telegram_bot::{Api, GetFile, FileRef};
+    /// # use tokio_core::reactor::Core;
+    /// #
+    /// # fn main() {
+    /// # let core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// # let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+    /// # if false {
+    /// # let file_id = FileRef::from("file_id");
+    /// let download_api = api.clone();
+    /// let future = api.send(GetFile::new(file_id))
+    ///     .and_then(move |file| download_api.download_file(&file));
+    /// future.and_then(|data| Ok(println!("{} bytes", data.len())));
+    /// # }
+    /// # }
+    /// ```
+    pub fn download_file(&self, file: &File) -> TelegramFuture<Vec<u8>> {
+        let future = self.download_file_stream(file).concat2();
+        TelegramFuture::new(Box::new(future))
+    }
+
+    /// Download a file returned by the `getFile` method, producing its contents in chunks.
+    pub fn download_file_stream(&self, file: &File) -> FileStream {
+        let limited = self.inner.base_url.is_official();
+        let request = match file_request(file, limited) {
+            Ok(request) => request,
+            Err(err) => return FileStream::new(Box::new(stream::once(Err(err)))),
+        };
+
+        let chunks = self.inner.connector.download(&self.inner.base_url, &self.inner.token, request);
+        if !limited {
+            return chunks
+        }
+
+        // `file_size` is optional, so the limit is checked on received data too.
+        let mut received = 0;
+        let chunks = chunks.and_then(move |chunk| {
+            received += chunk.len() as i64;
+            if received > MAX_DOWNLOAD_SIZE {
+                return Err(ErrorKind::FileTooLarge(received).into())
+            }
+            Ok(chunk)
+        });
+        FileStream::new(Box::new(chunks))
+    }
+
+    /// Download a file returned by the `getFile` method and write it to `path`.
+    ///
+    /// The file is written to `path` with the `.part` suffix and renamed after
+    /// the download is completed, the partial file is removed on errors.
+    pub fn download_file_to<P: AsRef<Path>>(&self, file: &File, path: P) -> TelegramFuture<()> {
+        let path = path.as_ref().to_path_buf();
+        let mut partial_name = path.file_name().map(OsStr::to_os_string).unwrap_or_default();
+        partial_name.push(".part");
+        let partial_path = path.with_file_name(partial_name);
+
+        let chunks = self.download_file_stream(file);
+        let output = result(fs::File::create(&partial_path)).map_err(From::from);
+
+        let future = output.and_then(move |output| {
+            chunks.fold(output, |mut output, chunk| -> Result<fs::File, Error> {
+                output.write_all(&chunk)?;
+                Ok(output)
+            })
+        });
+
+        let future = future.then(move |output| match output {
+            Ok(_) => Ok(fs::rename(&partial_path, &path)?),
+            Err(err) => {
+                let _ = fs::remove_file(&partial_path);
+                Err(err)
+            }
+        });
+
+        TelegramFuture::new(Box::new(future))
+    }
+}
+
+/// State of a request which may be sent several times.
+struct SendState {
+    request: HttpRequest,
+    attempt: u32,
+    migrated: bool,
+}
+
+impl SendState {
+    fn new(request: HttpRequest) -> Self {
+        SendState {
+            request: request,
+            attempt: 1,
+            migrated: false,
+        }
+    }
+}
+
+fn file_request(file: &File, limited: bool) -> Result<HttpRequest, Error> {
+    match file.file_size {
+        Some(size) if limited && size > MAX_DOWNLOAD_SIZE => return Err(ErrorKind::FileTooLarge(size).into()),
+        _ => (),
+    }
+
+    match file.file_path {
+        Some(ref path) => Ok(HttpRequest {
+            url: RequestUrl::file(path.as_str()),
+            method: Method::Get,
+            body: Body::Empty,
+        }),
+        None => Err(ErrorKind::FileUnavailable.into()),
+    }
 }
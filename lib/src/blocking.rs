@@ -0,0 +1,182 @@
+//! Blocking client for scripts and command line tools.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate telegram_bot;
+//! use telegram_bot::blocking::Api;
+//! use telegram_bot::{GetMe, Error};
+//!
+//! # fn main() {
+//! # fn run() -> Result<(), Error> {
+//! # let telegram_token = "token";
+//! let api = Api::new(telegram_token)?;
+//! let me = api.send(GetMe)?;
+//! println!("{:?}", me);
+//!
+//! for update in api.updates() {
+//!     println!("{:?}", update?);
+//! }
+//! # Ok(())
+//! # }
+//! # drop(run);
+//! # }
+//! ```
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use futures::{Future, Stream};
+use tokio_core::reactor::Core;
+
+use telegram_bot_raw::{Request, ResponseType, Update};
+
+use api;
+use errors::Error;
+use offset::OffsetStore;
+use stream::{UpdatesStream, UpdateAcks};
+
+/// Api which blocks the current thread until responses are received,
+/// it owns a `Core` to drive the requests.
+pub struct Api {
+    core: RefCell<Core>,
+    api: api::Api,
+}
+
+impl Api {
+    /// Create an `Api` with default configuration.
+    pub fn new<T: AsRef<str>>(token: T) -> Result<Self, Error> {
+        Api::from_config(api::Api::configure(token))
+    }
+
+    /// Create an `Api` with the `config` created by `telegram_bot::Api::configure`.
+    pub fn from_config(config: api::Config) -> Result<Self, Error> {
+        let core = Core::new()?;
+        let api = config.build(core.handle())?;
+        Ok(Api {
+            core: RefCell::new(core),
+            api: api,
+        })
+    }
+
+    /// Asynchronous `Api` driven by this one, for example to create a `SyncApi`.
+    pub fn inner(&self) -> &api::Api {
+        &self.api
+    }
+
+    /// Block until the `future` resolves, for example to download a file.
+    pub fn run<F: Future>(&self, future: F) -> Result<F::Item, F::Error> {
+        self.core.borrow_mut().run(future)
+    }
+
+    /// Send a request to the Telegram server and wait for a response.
+    pub fn send<Req: Request>(&self, request: Req) -> Result<<Req::Response as ResponseType>::Type, Error> {
+        self.run(self.api.send(request))
+    }
+
+    /// Send a request to the Telegram server and wait for a response, timing out after `duration`.
+    /// Returns `None` if timeout fired.
+    pub fn send_timeout<Req: Request>(&self, request: Req, duration: Duration)
+        -> Result<Option<<Req::Response as ResponseType>::Type>, Error>
+    {
+        self.run(self.api.send_timeout(request, duration))
+    }
+
+    /// Iterator over updates received with long polling, see `UpdatesStream`.
+    /// Errors are followed by the next requests, the iterator ends after a fatal error,
+    /// see `UpdatesStream::fatal_error`.
+    pub fn updates(&self) -> Updates {
+        Updates {
+            api: self,
+            stream: self.api.stream(),
+        }
+    }
+}
+
+/// Blocking iterator over updates, created with `Api::updates`.
+pub struct Updates<'a> {
+    api: &'a Api,
+    stream: UpdatesStream,
+}
+
+impl<'a> Updates<'a> {
+    /// Set timeout for long polling requests, see `UpdatesStream::timeout`.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.stream.timeout(timeout);
+        self
+    }
+
+    /// Set a delay between erroneous request and next request, see `UpdatesStream::error_delay`.
+    pub fn error_delay(&mut self, delay: Duration) -> &mut Self {
+        self.stream.error_delay(delay);
+        self
+    }
+
+    /// Underlying stream to set other options, like `UpdatesStream::allowed_updates`.
+    pub fn stream(&mut self) -> &mut UpdatesStream {
+        &mut self.stream
+    }
+
+    /// Continue from the offset saved in the `store`, see `UpdatesStream::with_offset_store`.
+    pub fn with_offset_store<S: OffsetStore + 'static>(&mut self, store: S) -> &mut Self {
+        self.stream.with_offset_store(store);
+        self
+    }
+
+    /// Advance the offset only for acknowledged updates, see `UpdatesStream::manual_ack`.
+    pub fn manual_ack(&mut self) -> UpdateAcks {
+        self.stream.manual_ack()
+    }
+}
+
+impl<'a> Iterator for Updates<'a> {
+    type Item = Result<Update, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.api.run(self.stream.by_ref().into_future()) {
+            Ok((update, _)) => update.map(Ok),
+            Err((error, _)) => Some(Err(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use telegram_bot_raw::{GetMe, GetUpdates, UserId};
+
+    use connector::MockConnector;
+    use super::*;
+
+    fn api(mock: &MockConnector) -> Api {
+        Api::from_config(api::Api::configure("token").connector(mock.boxed())).unwrap()
+    }
+
+    #[test]
+    fn send() {
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+
+        assert_eq!(api(&mock).send(GetMe).unwrap().first_name, "Bot");
+        assert!(api(&mock).send(GetMe).is_err());
+    }
+
+    #[test]
+    fn send_timeout() {
+        let mock = MockConnector::new();
+        let updates = api(&mock).send_timeout(GetUpdates::new(), Duration::from_millis(10)).unwrap();
+        assert!(updates.is_none());
+    }
+
+    #[test]
+    fn iterate_updates() {
+        let mock = MockConnector::new();
+        mock.push_text_message(UserId::new(1), "first");
+        mock.push_text_message(UserId::new(1), "second");
+        let api = api(&mock);
+
+        let ids: Vec<_> = api.updates().take(2).map(|update| update.unwrap().id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+}
@@ -0,0 +1,141 @@
+//! Typed bot commands.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use telegram_bot_raw::{BotCommand, Message, User};
+
+use dispatcher::Command;
+
+/// Enum of commands understood by a bot, implemented with `#[derive(BotCommands)]`
+/// when the `derive` feature is enabled.
+///
+/// Every variant is a command named after the variant in snake case, e.g. `BanUser`
+/// is `/ban_user`, its fields are whitespace separated arguments parsed with `FromStr`.
+/// Variants accept `#[command(rename = "name", description = "text")]`, doc comments
+/// are used as descriptions by default.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[macro_use]
+/// extern crate telegram_bot;
+///
+/// use telegram_bot::BotCommands;
+///
+/// #[derive(BotCommands)]
+/// enum Command {
+///     /// Start the bot
+///     Start,
+///     #[command(description = "Ban a user for some minutes")]
+///     Ban { user: String, minutes: u32 },
+///     #[command(rename = "help", description = "Show this text")]
+///     Usage,
+/// }
+///
+/// // In a message handler:
+/// match Command::parse(&message, &me) {
+///     Ok(Command::Ban { user, minutes }) => ban(user, minutes),
+///     Ok(Command::Usage) => api.spawn(message.text_reply(Command::help())),
+///     Err(error) => api.spawn(message.text_reply(error.to_string())),
+///     // ...
+/// }
+/// ```
+pub trait BotCommands: Sized {
+    /// Convert a parsed `command` into `Self`.
+    fn from_command(command: &Command) -> Result<Self, CommandError>;
+
+    /// Commands with their descriptions, as expected by `setMyCommands`.
+    fn bot_commands() -> Vec<BotCommand>;
+
+    /// Help text with a line per command, its arguments and description.
+    fn help() -> String;
+
+    /// Parse the command at the start of the `message` sent to the bot `me`.
+    fn parse(message: &Message, me: &User) -> Result<Self, CommandError> {
+        let command = Command::parse(&message.kind).ok_or(CommandError::NotCommand)?;
+        if !command.is_addressed_to(me) {
+            return Err(CommandError::OtherBot(command.username.unwrap_or_default()))
+        }
+        Self::from_command(&command)
+    }
+}
+
+/// Reason why a message could not be parsed as a command, the `Display`
+/// implementation is suitable as a reply to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The message does not start with a command.
+    NotCommand,
+    /// The command is addressed to another bot.
+    OtherBot(String),
+    /// The command is not known.
+    UnknownCommand(String),
+    /// The command has less arguments than required.
+    MissingArgument {
+        command: String,
+        argument: String,
+    },
+    /// The command has more arguments than expected.
+    TooManyArguments {
+        command: String,
+        expected: usize,
+    },
+    /// The argument could not be parsed.
+    InvalidArgument {
+        command: String,
+        argument: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CommandError::NotCommand => write!(f, "Not a command"),
+            CommandError::OtherBot(ref username) => write!(f, "Command is addressed to @{}", username),
+            CommandError::UnknownCommand(ref command) => write!(f, "Unknown command /{}", command),
+            CommandError::MissingArgument { ref command, ref argument } => {
+                write!(f, "Missing argument <{}> of /{}", argument, command)
+            }
+            CommandError::TooManyArguments { ref command, expected } => {
+                write!(f, "Too many arguments of /{}, expected {}", command, expected)
+            }
+            CommandError::InvalidArgument { ref command, ref argument, ref value, ref reason } => {
+                write!(f, "Invalid argument <{}> of /{}: {:?}, {}", argument, command, value, reason)
+            }
+        }
+    }
+}
+
+impl error::Error for CommandError {}
+
+#[doc(hidden)]
+pub fn parse_argument<T>(command: &Command, argument: &str, value: Option<&String>) -> Result<T, CommandError>
+    where T: FromStr, T::Err: fmt::Display
+{
+    let value = value.ok_or_else(|| CommandError::MissingArgument {
+        command: command.name.clone(),
+        argument: argument.to_string(),
+    })?;
+
+    value.parse().map_err(|error: T::Err| CommandError::InvalidArgument {
+        command: command.name.clone(),
+        argument: argument.to_string(),
+        value: value.clone(),
+        reason: error.to_string(),
+    })
+}
+
+#[doc(hidden)]
+pub fn ensure_arguments_count(command: &Command, expected: usize) -> Result<(), CommandError> {
+    if command.args.len() > expected {
+        return Err(CommandError::TooManyArguments {
+            command: command.name.clone(),
+            expected: expected,
+        })
+    }
+    Ok(())
+}
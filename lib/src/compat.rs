@@ -0,0 +1,209 @@
+//! Interoperability with `std::future` and `async`/`await`.
+//!
+//! `TelegramFuture` and `SyncFuture` implement `std::future::Future` and can be awaited in `async` code,
+//! streams like `UpdatesStream` can be awaited item by item with `next`. The IO of an `Api`
+//! is still driven by the `tokio_core` reactor it was built with, so `async` code should be
+//! converted with `from_async` and run on the `Core`.
+//!
+//! This module helps to migrate existing code step by step, the `telegram-bot-async` crate
+//! provides an `Api` for `tokio` 1.x with the same request types.
+//!
+//! # Examples
+//!
+//! ```rust,no_run,edition2018
+//! # fn main() {
+//! # let telegram_token = "token";
+//! use telegram_bot::{Api, GetMe};
+//! use telegram_bot::compat::{from_async, next};
+//! use tokio_core::reactor::Core;
+//!
+//! let mut core = Core::new().unwrap();
+//! let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+//!
+//! core.run(from_async(async move {
+//!     let me = api.send(GetMe).await?;
+//!     let mut updates = api.stream();
+//!     while let Some(update) = next(&mut updates).await {
+//!         println!("{} got {:?}", me.first_name, update?);
+//!     }
+//!     Ok::<_, telegram_bot::Error>(())
+//! })).unwrap();
+//! # }
+//! ```
+
+use std::future::Future as StdFuture;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll as StdPoll, Wake, Waker};
+
+use futures::{Async, Future, Poll, Stream};
+use futures::executor::{self, Notify, NotifyHandle};
+use futures::task::{self, Task};
+
+use errors::Error;
+use future::TelegramFuture;
+use sync_api::SyncFuture;
+
+/// The future is driven by the `tokio_core` reactor of the `Api`, so it must be awaited
+/// inside the `Core` of the `Api`, e.g. converted with `from_async` and run with `Core::run`.
+///
+/// Awaiting it from any other executor, like a `tokio` 1.x runtime, hangs forever
+/// because nothing drives the `Core`. Use the `telegram-bot-async` crate there.
+impl<T> StdFuture for TelegramFuture<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> StdPoll<Self::Output> {
+        poll_future(self.get_mut(), cx)
+    }
+}
+
+/// The request is sent by the `Api` on the thread running its `Core`, so the future
+/// completes only while that `Core` is running.
+impl<T> StdFuture for SyncFuture<T> {
+    type Output = Result<T, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> StdPoll<Self::Output> {
+        poll_future(self.get_mut(), cx)
+    }
+}
+
+fn poll_future<F: Future>(future: &mut F, cx: &mut Context) -> StdPoll<Result<F::Item, F::Error>> {
+    match executor::spawn(future).poll_future_notify(&notify_handle(cx), 0) {
+        Ok(Async::Ready(value)) => StdPoll::Ready(Ok(value)),
+        Ok(Async::NotReady) => StdPoll::Pending,
+        Err(error) => StdPoll::Ready(Err(error)),
+    }
+}
+
+/// Future resolving to the next item of a stream, see `next`.
+#[must_use = "futures do nothing unless polled"]
+pub struct Next<'s, S: 's> {
+    stream: &'s mut S,
+}
+
+/// Returns a `std::future::Future` resolving to the next item of the `stream`
+/// or `None` if the stream is finished.
+pub fn next<S: Stream>(stream: &mut S) -> Next<S> {
+    Next {
+        stream: stream,
+    }
+}
+
+impl<'s, S: Stream> StdFuture for Next<'s, S> {
+    type Output = Option<Result<S::Item, S::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> StdPoll<Self::Output> {
+        let stream = &mut *self.get_mut().stream;
+        match executor::spawn(stream).poll_stream_notify(&notify_handle(cx), 0) {
+            Ok(Async::Ready(item)) => StdPoll::Ready(item.map(Ok)),
+            Ok(Async::NotReady) => StdPoll::Pending,
+            Err(error) => StdPoll::Ready(Some(Err(error))),
+        }
+    }
+}
+
+/// `futures` 0.1 future driving a `std::future::Future`, see `from_async`.
+#[must_use = "futures do nothing unless polled"]
+pub struct Compat<F> {
+    inner: Pin<Box<F>>,
+}
+
+/// Convert a `std::future::Future` resolving to `Result`, like an `async` block,
+/// into a `futures` 0.1 future which can be run by `tokio_core`.
+pub fn from_async<F, T, E>(future: F) -> Compat<F> where F: StdFuture<Output=Result<T, E>> {
+    Compat {
+        inner: Box::pin(future),
+    }
+}
+
+impl<F, T, E> Future for Compat<F> where F: StdFuture<Output=Result<T, E>> {
+    type Item = T;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<T, E> {
+        let waker = Waker::from(Arc::new(TaskWaker(task::current())));
+        match self.inner.as_mut().poll(&mut Context::from_waker(&waker)) {
+            StdPoll::Ready(Ok(value)) => Ok(Async::Ready(value)),
+            StdPoll::Ready(Err(error)) => Err(error),
+            StdPoll::Pending => Ok(Async::NotReady),
+        }
+    }
+}
+
+/// Wakes a `futures` 0.1 task from a `std` context.
+struct TaskWaker(Task);
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.notify()
+    }
+}
+
+/// Wakes a `std` waker from a `futures` 0.1 task.
+struct WakerNotify(Waker);
+
+impl Notify for WakerNotify {
+    fn notify(&self, _id: usize) {
+        self.0.wake_by_ref()
+    }
+}
+
+fn notify_handle(cx: &Context) -> NotifyHandle {
+    NotifyHandle::from(Arc::new(WakerNotify(cx.waker().clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{GetMe, GetUpdates, UserId};
+
+    use api::Api;
+    use connector::MockConnector;
+    use super::*;
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    fn await_telegram_future() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let me = core.run(from_async(api.send(GetMe))).unwrap();
+        assert_eq!(me.first_name, "Bot");
+    }
+
+    #[test]
+    fn wake_on_timeout() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let future = api.send_timeout(GetUpdates::new(), Duration::from_millis(10));
+        assert_eq!(core.run(from_async(future)).unwrap().map(|updates| updates.len()), None);
+    }
+
+    #[test]
+    fn next_update() {
+        let core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.push_text_message(UserId::new(1), "text");
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let mut stream = api.stream();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut next = next(&mut stream);
+        match Pin::new(&mut next).poll(&mut Context::from_waker(&waker)) {
+            StdPoll::Ready(Some(Ok(update))) => assert_eq!(update.id, 1),
+            _ => panic!("update is not ready"),
+        }
+    }
+}
@@ -1,10 +1,23 @@
 use std::fmt::Debug;
 
+use futures::{Future, stream};
+
+use download::{FileStream, NewFileStream};
 use future::TelegramFuture;
 
-use telegram_bot_raw::{HttpRequest, HttpResponse};
+use telegram_bot_raw::{BaseUrl, HttpRequest, HttpResponse};
 
 /// Connector provides basic IO with Telegram Bot API server.
 pub trait Connector: Debug {
-    fn request(&self, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse>;
+    /// Send a request to the Bot API server located at `base_url`.
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse>;
+
+    /// Download a file, producing its contents in chunks.
+    ///
+    /// The default implementation simply buffers the response of `request`. Connectors
+    /// should override it to stream the body and to fail on non-successful HTTP status.
+    fn download(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> FileStream {
+        let chunks = self.request(base_url, token, req).map(|response| stream::iter_ok(response.body));
+        FileStream::new(Box::new(chunks.flatten_stream()))
+    }
 }
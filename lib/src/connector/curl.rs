@@ -1,26 +1,38 @@
 //! Connector with tokio-curl backend.
 
 use std::fmt;
+use std::str;
 use std::sync::Arc;
 use std::rc::Rc;
 
 use antidote::Mutex;
-use curl::easy::{Easy, List};
-use futures::Future;
+use curl::easy::{Easy, Form, List};
+use futures::{Future, Stream, stream};
 use futures::future::result;
+use futures::sync::mpsc;
 use tokio_core::reactor::Handle;
 use tokio_curl::Session;
 
-use errors::Error;
+use download::{FileStream, NewFileStream};
+use errors::{Error, ErrorKind};
 use future::{TelegramFuture, NewTelegramFuture};
 
-use telegram_bot_raw::{HttpRequest, HttpResponse, Method, Body};
+use telegram_bot_raw::{BaseUrl, HttpRequest, HttpResponse, Method, Body, MultipartValue};
 
 use super::_base::Connector;
+use super::proxy::Proxy;
 
 /// This connector uses `tokio-curl` backend.
 pub struct CurlConnector {
-    inner: Rc<Session>
+    inner: Rc<Session>,
+    proxy: ProxySetting,
+}
+
+enum ProxySetting {
+    /// Proxy configured by libcurl from environment variables.
+    Env,
+    Disabled,
+    Specified(Proxy),
 }
 
 impl fmt::Debug for CurlConnector {
@@ -30,18 +42,43 @@ impl fmt::Debug for CurlConnector {
 }
 
 impl CurlConnector {
+    /// Create a connector which uses the proxy configured with environment variables
+    /// the same way as the `curl` tool does.
     pub fn new(handle: &Handle) -> Self {
         CurlConnector {
-            inner: Rc::new(Session::new(handle.clone()))
+            inner: Rc::new(Session::new(handle.clone())),
+            proxy: ProxySetting::Env,
+        }
+    }
+
+    /// Create a connector which sends requests through the `proxy`
+    /// or directly if it's `None`.
+    pub fn with_proxy(handle: &Handle, proxy: Option<Proxy>) -> Self {
+        CurlConnector {
+            inner: Rc::new(Session::new(handle.clone())),
+            proxy: proxy.map_or(ProxySetting::Disabled, ProxySetting::Specified),
         }
     }
 
-    fn create_request(&self, token: &str, request: HttpRequest) -> Result<(Easy, Arc<Mutex<Vec<u8>>>), Error> {
+    fn create_request(&self, base_url: &BaseUrl, token: &str, request: HttpRequest) -> Result<(Easy, Arc<Mutex<Vec<u8>>>), Error> {
         let mut handle = Easy::new();
 
-        let url = request.url.url(token);
+        let url = request.url.url(base_url, token);
         handle.url(&url)?;
 
+        match self.proxy {
+            ProxySetting::Env => (),
+            ProxySetting::Disabled => handle.proxy("")?,
+            ProxySetting::Specified(ref proxy) => {
+                handle.proxy(&proxy.url())?;
+                if let Some((username, password)) = proxy.get_credentials() {
+                    handle.proxy_username(username)?;
+                    handle.proxy_password(password)?;
+                }
+                handle.noproxy(&proxy.no_proxy_list())?;
+            }
+        }
+
         match request.method {
             Method::Get => handle.get(true)?,
             Method::Post => handle.post(true)?,
@@ -56,6 +93,34 @@ impl CurlConnector {
                 headers.append(&format!("Content-Type: application/json"))?;
                 handle.http_headers(headers)?;
             }
+            Body::Multipart(parts) => {
+                let mut form = Form::new();
+                for (name, value) in parts {
+                    let mut part = form.part(&name);
+                    match value {
+                        MultipartValue::Text(ref text) => {
+                            part.contents(text.as_bytes());
+                        }
+                        MultipartValue::Path { ref path, ref file_name, ref mime } => {
+                            part.file(path);
+                            if let Some(ref file_name) = *file_name {
+                                part.filename(file_name);
+                            }
+                            if let Some(ref mime) = *mime {
+                                part.content_type(mime);
+                            }
+                        }
+                        MultipartValue::Data { ref file_name, ref mime, ref data } => {
+                            part.buffer(file_name, data.clone());
+                            if let Some(ref mime) = *mime {
+                                part.content_type(mime);
+                            }
+                        }
+                    }
+                    part.add()?;
+                }
+                handle.httppost(form)?;
+            }
             body => panic!("Unknown body type {:?}", body)
         }
 
@@ -72,8 +137,8 @@ impl CurlConnector {
 }
 
 impl Connector for CurlConnector {
-    fn request(&self, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
-        let request = result(self.create_request(token, req));
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let request = result(self.create_request(base_url, token, req));
 
         let session = self.inner.clone();
         let request = request.and_then(move |(handle, result)| {
@@ -92,10 +157,88 @@ impl Connector for CurlConnector {
 
         TelegramFuture::new(Box::new(future))
     }
+
+    fn download(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> FileStream {
+        let (sender, receiver) = mpsc::unbounded::<Result<Vec<u8>, Error>>();
+        let state = Arc::new(Mutex::new(DownloadState { status: 0, failed: false }));
+
+        let header_state = state.clone();
+        let write_state = state.clone();
+        let request = self.create_request(base_url, token, req).and_then(move |(mut handle, _)| {
+            handle.header_function(move |line| {
+                if let Some(status) = status_code(line) {
+                    header_state.lock().status = status;
+                }
+                true
+            })?;
+
+            // Chunks are passed on as they are received, the transfer is aborted
+            // after an unsuccessful status or when the stream is dropped.
+            handle.write_function(move |data| {
+                let mut state = write_state.lock();
+                if state.status < 200 || state.status >= 300 {
+                    state.failed = true;
+                    let _ = sender.unbounded_send(Err(ErrorKind::DownloadFailed(state.status as u16).into()));
+                    return Ok(0)
+                }
+                match sender.unbounded_send(Ok(data.to_vec())) {
+                    Ok(()) => Ok(data.len()),
+                    Err(_) => {
+                        state.failed = true;
+                        Ok(0)
+                    }
+                }
+            })?;
+            Ok(handle)
+        });
+
+        let session = self.inner.clone();
+        let done = result(request).and_then(move |handle| {
+            session.perform(handle).then(move |result| -> Result<(), Error> {
+                let failed = state.lock().failed;
+                match result {
+                    Err(_) if failed => Ok(()),
+                    Err(err) => Err(err.into()),
+                    Ok(handle) => {
+                        let status = handle.response_code()?;
+                        if !failed && (status < 200 || status >= 300) {
+                            return Err(ErrorKind::DownloadFailed(status as u16).into())
+                        }
+                        Ok(())
+                    }
+                }
+            })
+        });
+
+        let chunks = receiver.map_err(|()| -> Error { unreachable!() }).and_then(|chunk| chunk);
+        let done = done.map(|()| stream::empty()).flatten_stream();
+        FileStream::new(Box::new(chunks.select(done)))
+    }
 }
 
-/// Returns default curl connector.
+/// Status of a download shared with the curl callbacks.
+struct DownloadState {
+    status: u32,
+    failed: bool,
+}
+
+/// Parses the status code of a response status line, e.g. `HTTP/1.1 200 OK`.
+fn status_code(line: &[u8]) -> Option<u32> {
+    let line = match str::from_utf8(line) {
+        Ok(line) if line.starts_with("HTTP/") => line,
+        _ => return None,
+    };
+    line.split_whitespace().nth(1).and_then(|code| code.parse().ok())
+}
+
+/// Returns default curl connector, the proxy is configured with environment variables,
+/// see `Proxy::from_env`.
 pub fn default_connector(handle: &Handle) -> Result<Box<Connector>, Error> {
-    let connector = CurlConnector::new(handle);
+    proxy_connector(handle, Proxy::from_env())
+}
+
+/// Returns curl connector which sends requests through the `proxy`.
+pub fn proxy_connector(handle: &Handle, proxy: Option<Proxy>) -> Result<Box<Connector>, Error> {
+    let connector = CurlConnector::with_proxy(handle, proxy);
     Ok(Box::new(connector))
 }
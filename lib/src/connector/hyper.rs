@@ -1,24 +1,34 @@
 //! Connector with hyper backend.
 
 use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
 use std::str::FromStr;
 use std::rc::Rc;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use futures::{Future, Stream};
-use futures::future::result;
+use futures::future::{ok, result};
+use futures::sync::oneshot;
 use hyper;
 use hyper::{Method, Uri};
 use hyper::client::{Client, Connect};
 use hyper::header::ContentType;
 use hyper_tls::HttpsConnector;
+use native_tls::TlsConnector;
 use tokio_core::reactor::Handle;
 
-use telegram_bot_raw::{HttpRequest, HttpResponse, Method as TelegramMethod, Body as TelegramBody};
+use telegram_bot_raw::{BaseUrl, HttpRequest, HttpResponse, Method as TelegramMethod, Body as TelegramBody};
+use telegram_bot_raw::{Multipart, MultipartValue};
 
-use errors::Error;
+use download::{FileStream, NewFileStream};
+use errors::{Error, ErrorKind};
 use future::{TelegramFuture, NewTelegramFuture};
 
 use super::_base::Connector;
+use super::proxy::Proxy;
+use super::tunnel::TunnelConnector;
 
 /// This connector uses `hyper` backend.
 pub struct HyperConnector<C> {
@@ -39,28 +49,45 @@ impl<C> HyperConnector<C> {
     }
 }
 
-impl<C: Connect> Connector for HyperConnector<C> {
-    fn request(&self, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
-        let uri = result(Uri::from_str(&req.url.url(token))).map_err(From::from);
+impl<C> HyperConnector<C> {
+    fn create_request(uri: Uri, req: HttpRequest) -> Box<Future<Item=hyper::client::Request, Error=Error>> {
+        let method = match req.method {
+            TelegramMethod::Get => Method::Get,
+            TelegramMethod::Post => Method::Post,
+        };
+        let mut http_request = hyper::client::Request::new(method, uri);
 
-        let client = self.inner.clone();
-        let request = uri.and_then(move |uri| {
-            let method = match req.method {
-                TelegramMethod::Get => Method::Get,
-                TelegramMethod::Post => Method::Post,
-            };
-            let mut http_request = hyper::client::Request::new(method, uri);
-
-            match req.body {
-                TelegramBody::Empty => (),
-                TelegramBody::Json(body) => {
-                    http_request.set_body(body);
-                    http_request.headers_mut().set(ContentType::json());
-                }
-                body => panic!("Unknown body type {:?}", body)
+        match req.body {
+            TelegramBody::Empty => (),
+            TelegramBody::Json(body) => {
+                http_request.set_body(body);
+                http_request.headers_mut().set(ContentType::json());
             }
+            TelegramBody::Multipart(parts) => {
+                let future = read_parts(parts).map(move |parts| {
+                    let boundary = multipart_boundary(&parts);
+                    http_request.set_body(multipart_body(&boundary, parts));
+                    http_request.headers_mut().set_raw(
+                        "Content-Type", format!("multipart/form-data; boundary={}", boundary)
+                    );
+                    http_request
+                });
+                return Box::new(future)
+            }
+            body => panic!("Unknown body type {:?}", body)
+        }
 
-            client.request(http_request).map_err(From::from)
+        Box::new(ok(http_request))
+    }
+}
+
+impl<C: Connect> Connector for HyperConnector<C> {
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let uri = result(Uri::from_str(&req.url.url(base_url, token))).map_err(From::from);
+
+        let client = self.inner.clone();
+        let request = uri.and_then(move |uri| Self::create_request(uri, req)).and_then(move |request| {
+            client.request(request).map_err(From::from)
         });
 
         let future = request.and_then(move |response| {
@@ -79,13 +106,179 @@ impl<C: Connect> Connector for HyperConnector<C> {
 
         TelegramFuture::new(Box::new(future))
     }
+
+    fn download(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> FileStream {
+        let uri = result(Uri::from_str(&req.url.url(base_url, token))).map_err(From::from);
+
+        let client = self.inner.clone();
+        let request = uri.and_then(move |uri| Self::create_request(uri, req)).and_then(move |request| {
+            client.request(request).map_err(From::from)
+        });
+
+        let chunks = request.and_then(|response| {
+            let status = response.status();
+            if !status.is_success() {
+                return Err(ErrorKind::DownloadFailed(status.as_u16()).into())
+            }
+            Ok(response.body().map(|chunk| chunk.to_vec()).map_err(From::from))
+        });
+
+        FileStream::new(Box::new(chunks.flatten_stream()))
+    }
+}
+
+/// Value of a multipart field with the contents of files in memory.
+enum Part {
+    Text(String),
+    File {
+        file_name: String,
+        mime: Option<String>,
+        data: Vec<u8>,
+    },
+}
+
+/// Read the files of `parts` on a separate thread, so that large uploads
+/// do not block the reactor.
+fn read_parts(parts: Multipart) -> Box<Future<Item=Vec<(String, Part)>, Error=Error>> {
+    let (sender, receiver) = oneshot::channel();
+    thread::spawn(move || {
+        let parts = parts.into_iter().map(|(name, value)| Ok((name, read_part(value)?))).collect();
+        let _ = sender.send(parts);
+    });
+
+    Box::new(receiver.then(|result| match result {
+        Ok(parts) => parts,
+        Err(_) => Err(io::Error::new(io::ErrorKind::Other, "reading of uploaded files failed").into()),
+    }))
 }
 
-/// Returns default hyper connector. Uses one resolve thread and `HttpsConnector`.
+fn read_part(value: MultipartValue) -> Result<Part, Error> {
+    Ok(match value {
+        MultipartValue::Text(text) => Part::Text(text),
+        MultipartValue::Path { path, file_name, mime } => {
+            let file_name = file_name.unwrap_or_else(|| {
+                path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default()
+            });
+            let mut data = Vec::new();
+            File::open(path)?.read_to_end(&mut data)?;
+            Part::File { file_name: file_name, mime: mime, data: data }
+        }
+        MultipartValue::Data { file_name, mime, data } => {
+            Part::File { file_name: file_name, mime: mime, data: data }
+        }
+    })
+}
+
+fn multipart_boundary(parts: &[(String, Part)]) -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos()).unwrap_or(0);
+
+    let mut counter = 0u32;
+    loop {
+        let boundary = format!("------------------------{:08x}{:08x}", nanos, counter);
+        let collides = parts.iter().any(|&(_, ref part)| {
+            let data = match *part {
+                Part::Text(ref text) => text.as_bytes(),
+                Part::File { ref data, .. } => data,
+            };
+            data.windows(boundary.len()).any(|window| window == boundary.as_bytes())
+        });
+        if !collides {
+            return boundary
+        }
+        counter += 1;
+    }
+}
+
+fn multipart_body(boundary: &str, parts: Vec<(String, Part)>) -> Vec<u8> {
+    let mut body = Vec::new();
+
+    for (name, part) in parts {
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        match part {
+            Part::Text(text) => {
+                body.extend_from_slice(format!(
+                    "Content-Disposition: form-data; name={}\r\n\r\n", quote(&name)
+                ).as_bytes());
+                body.extend_from_slice(text.as_bytes());
+            }
+            Part::File { file_name, mime, data } => {
+                let mime = mime.unwrap_or_else(|| "application/octet-stream".to_string());
+                body.extend_from_slice(format!(
+                    "Content-Disposition: form-data; name={}; filename={}\r\n", quote(&name), quote(&file_name)
+                ).as_bytes());
+                body.extend_from_slice(format!(
+                    "Content-Type: {}\r\n\r\n", mime.replace(|ch| ch == '\r' || ch == '\n', "")
+                ).as_bytes());
+                body.extend_from_slice(&data);
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    body
+}
+
+/// Quoted string for a `Content-Disposition` parameter, line breaks are removed
+/// so that a file name can not end the header.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '\r' | '\n' => (),
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(ch);
+            }
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Returns default hyper connector. Uses one resolve thread and `HttpsConnector`,
+/// the proxy is configured with environment variables, see `Proxy::from_env`.
 pub fn default_connector(handle: &Handle) -> Result<Box<Connector>, Error> {
-    let connector = HttpsConnector::new(1, handle).map_err(|err| {
+    proxy_connector(handle, Proxy::from_env())
+}
+
+/// Returns hyper connector which sends requests through the `proxy`.
+/// Uses one resolve thread and `HttpsConnector`.
+pub fn proxy_connector(handle: &Handle, proxy: Option<Proxy>) -> Result<Box<Connector>, Error> {
+    let tls = TlsConnector::builder().and_then(|builder| builder.build()).map_err(|err| {
         ::std::io::Error::new(::std::io::ErrorKind::Other, format!("tls error: {}", err))
     })?;
+    let connector = HttpsConnector::from((TunnelConnector::new(1, handle, proxy), tls));
     let config = Client::configure().connector(connector);
     Ok(Box::new(HyperConnector::new(config.build(handle))))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multipart_quotes_names() {
+        let parts = vec![
+            ("caption".to_string(), Part::Text("text".to_string())),
+            ("photo\r\nX-Injected: 1".to_string(), Part::File {
+                file_name: "say \"hi\"\\.png".to_string(),
+                mime: Some("image/png".to_string()),
+                data: b"data".to_vec(),
+            }),
+        ];
+        let body = String::from_utf8(multipart_body("boundary", parts)).unwrap();
+
+        assert_eq!(body, "--boundary\r\n\
+            Content-Disposition: form-data; name=\"caption\"\r\n\r\n\
+            text\r\n\
+            --boundary\r\n\
+            Content-Disposition: form-data; name=\"photoX-Injected: 1\"; filename=\"say \\\"hi\\\"\\\\.png\"\r\n\
+            Content-Type: image/png\r\n\r\n\
+            data\r\n\
+            --boundary--\r\n");
+    }
+}
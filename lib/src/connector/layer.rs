@@ -0,0 +1,365 @@
+//! Layers adding behaviour to connectors.
+//!
+//! Layers are added with `Config::layer` and wrap the connector of an `Api`,
+//! layers added later wrap the ones added earlier and see requests first.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use futures::Future;
+use serde_json::{self, Value};
+
+use telegram_bot_raw::{BaseUrl, Body, HttpRequest, HttpResponse, MultipartValue, RequestUrl};
+
+use download::FileStream;
+use future::{TelegramFuture, NewTelegramFuture};
+
+use super::_base::Connector;
+
+/// Replacement of the bot token in logs.
+const REDACTED: &str = "<redacted>";
+
+/// Wraps a connector to add behaviour to all requests sent through it.
+pub trait Layer: fmt::Debug {
+    fn layer(&self, inner: Box<Connector>) -> Box<Connector>;
+}
+
+/// Logs requests and responses with the `log` crate at debug level,
+/// the bot token is redacted from urls and bodies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoggingLayer;
+
+impl LoggingLayer {
+    pub fn new() -> Self {
+        LoggingLayer
+    }
+}
+
+impl Layer for LoggingLayer {
+    fn layer(&self, inner: Box<Connector>) -> Box<Connector> {
+        Box::new(Logging { inner: inner })
+    }
+}
+
+#[derive(Debug)]
+struct Logging {
+    inner: Box<Connector>,
+}
+
+impl Connector for Logging {
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let url = redact(&req.url.url(base_url, token), token);
+        debug!("request url={} body={}", url, redact(&describe_body(&req.body), token));
+
+        let started = Instant::now();
+        let token = token.to_string();
+        let future = self.inner.request(base_url, &token, req).then(move |result| {
+            let elapsed = millis(started.elapsed());
+            match result {
+                Ok(response) => {
+                    let body = response.body.as_ref()
+                        .map(|body| String::from_utf8_lossy(body).into_owned()).unwrap_or_default();
+                    debug!("response url={} elapsed_ms={} body={}", url, elapsed, redact(&body, &token));
+                    Ok(response)
+                }
+                Err(error) => {
+                    debug!("response url={} elapsed_ms={} error={}", url, elapsed, redact(&error.to_string(), &token));
+                    Err(error)
+                }
+            }
+        });
+
+        TelegramFuture::new(Box::new(future))
+    }
+
+    fn download(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> FileStream {
+        debug!("download url={}", redact(&req.url.url(base_url, token), token));
+        self.inner.download(base_url, token, req)
+    }
+}
+
+fn redact(text: &str, token: &str) -> String {
+    if token.is_empty() {
+        return text.to_string()
+    }
+    text.replace(token, REDACTED)
+}
+
+fn describe_body(body: &Body) -> String {
+    match *body {
+        Body::Json(ref body) => String::from_utf8_lossy(body).into_owned(),
+        Body::Multipart(ref parts) => {
+            let parts: Vec<_> = parts.iter().map(|&(ref name, ref value)| match *value {
+                MultipartValue::Text(ref text) => format!("{}={:?}", name, text),
+                _ => format!("{}=<file>", name),
+            }).collect();
+            format!("multipart({})", parts.join(", "))
+        }
+        _ => "empty".to_string(),
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_secs() * 1000 + u64::from(duration.subsec_nanos() / 1_000_000)
+}
+
+/// Counters of requests to a single Bot API method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MethodMetrics {
+    /// Number of completed requests.
+    pub requests: u64,
+    /// Number of requests which failed or were answered with a Telegram error.
+    pub errors: u64,
+    /// Sum of latencies of completed requests.
+    pub total_latency: Duration,
+    /// Maximal latency of a completed request.
+    pub max_latency: Duration,
+}
+
+/// Counters of requests per Bot API method, shared between clones.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorMetrics {
+    methods: Rc<RefCell<HashMap<&'static str, MethodMetrics>>>,
+}
+
+impl ConnectorMetrics {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Counters of requests to the `method`.
+    pub fn method(&self, method: &str) -> MethodMetrics {
+        self.methods.borrow().get(method).cloned().unwrap_or_default()
+    }
+
+    /// Counters of requests to all methods called so far.
+    pub fn methods(&self) -> HashMap<String, MethodMetrics> {
+        self.methods.borrow().iter().map(|(method, metrics)| (method.to_string(), *metrics)).collect()
+    }
+
+    fn record(&self, method: &'static str, latency: Duration, error: bool) {
+        let mut methods = self.methods.borrow_mut();
+        let metrics = methods.entry(method).or_insert_with(Default::default);
+        metrics.requests += 1;
+        if error {
+            metrics.errors += 1;
+        }
+        metrics.total_latency += latency;
+        if latency > metrics.max_latency {
+            metrics.max_latency = latency;
+        }
+    }
+}
+
+/// Counts requests, errors and latencies per Bot API method, file downloads are not counted.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate telegram_bot;
+/// # extern crate tokio_core;
+/// use telegram_bot::{Api, MetricsLayer};
+/// use tokio_core::reactor::Core;
+///
+/// # fn main() {
+/// let core = Core::new().unwrap();
+/// # let telegram_token = "token";
+/// let layer = MetricsLayer::new();
+/// let metrics = layer.metrics();
+/// let api = Api::configure(telegram_token).layer(layer).build(core.handle()).unwrap();
+///
+/// println!("sent messages: {}", metrics.method("sendMessage").requests);
+/// # drop(api);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MetricsLayer {
+    metrics: ConnectorMetrics,
+}
+
+impl MetricsLayer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Handle to read the counters collected by the layer.
+    pub fn metrics(&self) -> ConnectorMetrics {
+        self.metrics.clone()
+    }
+}
+
+impl Layer for MetricsLayer {
+    fn layer(&self, inner: Box<Connector>) -> Box<Connector> {
+        Box::new(Metrics {
+            inner: inner,
+            metrics: self.metrics.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Metrics {
+    inner: Box<Connector>,
+    metrics: ConnectorMetrics,
+}
+
+impl Connector for Metrics {
+    fn request(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let method = match req.url {
+            RequestUrl::Method(method) => method,
+            _ => return self.inner.request(base_url, token, req),
+        };
+
+        let started = Instant::now();
+        let metrics = self.metrics.clone();
+        let future = self.inner.request(base_url, token, req).then(move |result| {
+            let error = match result {
+                Ok(ref response) => !is_ok(response),
+                Err(_) => true,
+            };
+            metrics.record(method, started.elapsed(), error);
+            result
+        });
+
+        TelegramFuture::new(Box::new(future))
+    }
+
+    fn download(&self, base_url: &BaseUrl, token: &str, req: HttpRequest) -> FileStream {
+        self.inner.download(base_url, token, req)
+    }
+}
+
+fn is_ok(response: &HttpResponse) -> bool {
+    response.body.as_ref()
+        .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+        .and_then(|body| body.get("ok").and_then(Value::as_bool))
+        .unwrap_or(false)
+}
+
+/// Changes every request before it is sent, including file downloads.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate telegram_bot;
+/// # extern crate tokio_core;
+/// use telegram_bot::{Api, MapRequestLayer, Method};
+/// use tokio_core::reactor::Core;
+///
+/// # fn main() {
+/// let core = Core::new().unwrap();
+/// # let telegram_token = "token";
+/// let api = Api::configure(telegram_token)
+///     .layer(MapRequestLayer::new(|request| request.method = Method::Post))
+///     .build(core.handle()).unwrap();
+/// # drop(api);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MapRequestLayer {
+    map: Rc<Fn(&mut HttpRequest)>,
+}
+
+impl MapRequestLayer {
+    pub fn new<F>(map: F) -> Self where F: Fn(&mut HttpRequest) + 'static {
+        MapRequestLayer {
+            map: Rc::new(map),
+        }
+    }
+}
+
+impl fmt::Debug for MapRequestLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MapRequestLayer")
+    }
+}
+
+impl Layer for MapRequestLayer {
+    fn layer(&self, inner: Box<Connector>) -> Box<Connector> {
+        Box::new(MapRequest {
+            inner: inner,
+            layer: self.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct MapRequest {
+    inner: Box<Connector>,
+    layer: MapRequestLayer,
+}
+
+impl Connector for MapRequest {
+    fn request(&self, base_url: &BaseUrl, token: &str, mut req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        (self.layer.map)(&mut req);
+        self.inner.request(base_url, token, req)
+    }
+
+    fn download(&self, base_url: &BaseUrl, token: &str, mut req: HttpRequest) -> FileStream {
+        (self.layer.map)(&mut req);
+        self.inner.download(base_url, token, req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{ChatId, GetMe, CanSendMessage};
+
+    use api::Api;
+    use connector::MockConnector;
+    use super::*;
+
+    #[test]
+    fn redact_token() {
+        let url = RequestUrl::method("getMe").url(&BaseUrl::default(), "123:secret");
+        assert_eq!(redact(&url, "123:secret"), "https://api.telegram.org/bot<redacted>/getMe");
+        assert_eq!(describe_body(&Body::Multipart(vec![
+            ("chat_id".to_string(), MultipartValue::Text("1".to_string())),
+            ("photo".to_string(), MultipartValue::Data { file_name: "a.png".to_string(), mime: None, data: vec![] }),
+        ])), r#"multipart(chat_id="1", photo=<file>)"#);
+    }
+
+    #[test]
+    fn count_requests_per_method() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+        mock.respond_error("sendMessage", 403, "Forbidden: bot was blocked by the user", None);
+
+        let layer = MetricsLayer::new();
+        let metrics = layer.metrics();
+        let api = Api::configure("token")
+            .connector(mock.boxed())
+            .layer(LoggingLayer::new())
+            .layer(layer)
+            .build(core.handle()).unwrap();
+
+        core.run(api.send(GetMe)).unwrap();
+        assert!(core.run(api.send(ChatId::new(1).text("text"))).is_err());
+
+        assert_eq!(metrics.methods().len(), 2);
+        assert_eq!((metrics.method("getMe").requests, metrics.method("getMe").errors), (1, 0));
+        assert_eq!((metrics.method("sendMessage").requests, metrics.method("sendMessage").errors), (1, 1));
+        assert_eq!(metrics.method("getChat"), MethodMetrics::default());
+    }
+
+    #[test]
+    fn map_requests() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+
+        let api = Api::configure("token")
+            .connector(mock.boxed())
+            .layer(MapRequestLayer::new(|request| request.url = RequestUrl::method("getMe")))
+            .build(core.handle()).unwrap();
+
+        core.run(api.send(ChatId::new(1).text("text"))).ok();
+        assert_eq!(mock.requests()[0].method(), Some("getMe"));
+    }
+}
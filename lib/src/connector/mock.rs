@@ -0,0 +1,373 @@
+//! In-memory connector for testing bots offline.
+//!
+//! `MockConnector` records every request and replies with scripted responses.
+//! It is available with the `testing` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[macro_use] extern crate serde_json;
+//! # extern crate telegram_bot;
+//! # extern crate tokio_core;
+//! # #[cfg(feature = "testing")]
+//! # fn main() {
+//! use telegram_bot::{Api, ChatId};
+//! use telegram_bot::connector::mock::MockConnector;
+//! use telegram_bot::prelude::*;
+//! use tokio_core::reactor::Core;
+//!
+//! let mut core = Core::new().unwrap();
+//! let mock = MockConnector::new();
+//! mock.respond("sendMessage", json!({
+//!     "message_id": 1, "date": 0, "text": "Hello",
+//!     "from": {"id": 1, "first_name": "Bot"},
+//!     "chat": {"id": 2, "type": "private", "first_name": "User"}
+//! }));
+//!
+//! let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+//! core.run(api.send(ChatId::new(2).text("Hello"))).unwrap();
+//!
+//! mock.assert_message_sent(ChatId::new(2), "Hello");
+//! # }
+//! # #[cfg(not(feature = "testing"))]
+//! # fn main() {}
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+
+use futures::future::{empty, result};
+use serde_json::{self, Value};
+
+use telegram_bot_raw::{BaseUrl, HttpRequest, HttpResponse, RequestUrl, Body, MultipartValue};
+use telegram_bot_raw::{Integer, ToChatRef, UserId};
+
+use future::{TelegramFuture, NewTelegramFuture};
+
+use super::_base::Connector;
+
+/// Request received by the `MockConnector`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    /// Url of the request.
+    pub url: RequestUrl,
+    /// Parameters of the request decoded as JSON object, uploaded files are omitted.
+    pub body: Value,
+    /// The request itself.
+    pub request: HttpRequest,
+}
+
+impl RecordedRequest {
+    fn new(request: HttpRequest) -> Self {
+        let body = match request.body {
+            Body::Json(ref body) => serde_json::from_slice(body).unwrap_or(Value::Null),
+            Body::Multipart(ref parts) => Value::Object(parts.iter().filter_map(|&(ref name, ref value)| {
+                match *value {
+                    MultipartValue::Text(ref text) => Some((name.clone(), Value::String(text.clone()))),
+                    _ => None,
+                }
+            }).collect()),
+            _ => Value::Null,
+        };
+
+        RecordedRequest {
+            url: request.url.clone(),
+            body: body,
+            request: request,
+        }
+    }
+
+    /// Name of the Bot API method, `None` for file downloads.
+    pub fn method(&self) -> Option<&str> {
+        match self.url {
+            RequestUrl::Method(method) => Some(method),
+            _ => None,
+        }
+    }
+
+    /// Value of the request parameter.
+    pub fn param(&self, name: &str) -> Option<&Value> {
+        self.body.get(name)
+    }
+}
+
+/// Connector which records requests and replies with scripted responses.
+///
+/// Responses are looked up in the following order: responses queued for the method
+/// with `respond*` methods, the handler set with `handler`, updates queued with
+/// `push_update` for `getUpdates` requests. If there are no updates, `getUpdates`
+/// never completes, like a long polling request without updates. All other
+/// requests fail with a Telegram error.
+///
+/// Clones share recorded requests and scripted responses, so a clone can be passed
+/// to the `Api` while the original is used for assertions.
+#[derive(Clone)]
+pub struct MockConnector {
+    inner: Rc<MockInner>,
+}
+
+struct MockInner {
+    requests: RefCell<Vec<RecordedRequest>>,
+    responses: RefCell<HashMap<String, VecDeque<HttpResponse>>>,
+    handler: RefCell<Option<Box<Fn(&RecordedRequest) -> Option<HttpResponse>>>>,
+    updates: RefCell<VecDeque<Value>>,
+    next_update_id: Cell<Integer>,
+    next_message_id: Cell<Integer>,
+}
+
+impl MockConnector {
+    pub fn new() -> Self {
+        MockConnector {
+            inner: Rc::new(MockInner {
+                requests: RefCell::new(Vec::new()),
+                responses: RefCell::new(HashMap::new()),
+                handler: RefCell::new(None),
+                updates: RefCell::new(VecDeque::new()),
+                next_update_id: Cell::new(1),
+                next_message_id: Cell::new(1),
+            })
+        }
+    }
+
+    /// Boxed clone of the connector to pass to `Config::connector`.
+    pub fn boxed(&self) -> Box<Connector> {
+        Box::new(self.clone())
+    }
+
+    /// Reply to the next `method` request successfully with `result`.
+    pub fn respond(&self, method: &str, result: Value) -> &Self {
+        self.respond_raw(method, response_body(json!({"ok": true, "result": result})))
+    }
+
+    /// Reply to the next `method` request with a Telegram error.
+    pub fn respond_error(&self, method: &str, error_code: Integer, description: &str, parameters: Option<Value>)
+        -> &Self
+    {
+        let mut body = json!({"ok": false, "error_code": error_code, "description": description});
+        if let Some(parameters) = parameters {
+            body["parameters"] = parameters;
+        }
+        self.respond_raw(method, response_body(body))
+    }
+
+    /// Reply to the next `method` request with `response`.
+    pub fn respond_raw(&self, method: &str, response: HttpResponse) -> &Self {
+        self.inner.responses.borrow_mut()
+            .entry(method.to_string()).or_insert_with(VecDeque::new)
+            .push_back(response);
+        self
+    }
+
+    /// Reply to the next file download with `data`.
+    pub fn respond_download(&self, data: Vec<u8>) -> &Self {
+        self.respond_raw("", HttpResponse { body: Some(data) })
+    }
+
+    /// Reply to requests without queued responses with `handler`,
+    /// `None` falls back to the default behaviour.
+    pub fn handler<F>(&self, handler: F) -> &Self
+        where F: Fn(&RecordedRequest) -> Option<HttpResponse> + 'static
+    {
+        *self.inner.handler.borrow_mut() = Some(Box::new(handler));
+        self
+    }
+
+    /// Queue an update to be returned from `getUpdates`,
+    /// `update_id` is assigned automatically if missing.
+    pub fn push_update(&self, mut update: Value) -> &Self {
+        let id = self.inner.next_update_id.get();
+        match update.get("update_id").and_then(Value::as_i64) {
+            Some(update_id) => self.inner.next_update_id.set(update_id + 1),
+            None => {
+                update["update_id"] = json!(id);
+                self.inner.next_update_id.set(id + 1);
+            }
+        }
+        self.inner.updates.borrow_mut().push_back(update);
+        self
+    }
+
+    /// Queue a text message sent by the `user` to the bot in a private chat.
+    /// Text starting with `/` is marked as a bot command.
+    pub fn push_text_message<U>(&self, user: U, text: &str) -> &Self where U: Into<UserId> {
+        let user = Integer::from(user.into());
+        let message_id = self.inner.next_message_id.get();
+        self.inner.next_message_id.set(message_id + 1);
+
+        let mut message = json!({
+            "message_id": message_id,
+            "date": 0,
+            "from": {"id": user, "first_name": "User"},
+            "chat": {"id": user, "type": "private", "first_name": "User"},
+            "text": text,
+        });
+        if text.starts_with('/') {
+            let length = text.split_whitespace().next().unwrap_or("").encode_utf16().count();
+            message["entities"] = json!([{"type": "bot_command", "offset": 0, "length": length}]);
+        }
+        self.push_update(json!({"message": message}))
+    }
+
+    /// All requests received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.inner.requests.borrow().clone()
+    }
+
+    /// Requests to the `method` received so far.
+    pub fn requests_to(&self, method: &str) -> Vec<RecordedRequest> {
+        self.inner.requests.borrow().iter()
+            .filter(|request| request.method() == Some(method))
+            .cloned().collect()
+    }
+
+    /// Forget all received requests.
+    pub fn clear_requests(&self) {
+        self.inner.requests.borrow_mut().clear()
+    }
+
+    /// Panics unless a `sendMessage` request with `text` was sent to the `chat`.
+    pub fn assert_message_sent<C>(&self, chat: C, text: &str) where C: ToChatRef {
+        let chat_id = serde_json::to_value(chat.to_chat_ref()).unwrap();
+        let sent = self.requests_to("sendMessage");
+        let found = sent.iter().any(|request| {
+            request.param("chat_id") == Some(&chat_id) && request.param("text") == Some(&json!(text))
+        });
+
+        if !found {
+            let sent: Vec<_> = sent.iter().map(|request| &request.body).collect();
+            panic!("message {:?} was not sent to chat {}, sent messages: {:?}", text, chat_id, sent)
+        }
+    }
+
+    fn response(&self, request: &RecordedRequest) -> Option<HttpResponse> {
+        let method = request.method().unwrap_or("");
+
+        let queued = self.inner.responses.borrow_mut().get_mut(method).and_then(VecDeque::pop_front);
+        if queued.is_some() {
+            return queued
+        }
+
+        if let Some(ref handler) = *self.inner.handler.borrow() {
+            if let Some(response) = handler(request) {
+                return Some(response)
+            }
+        }
+
+        if method == "getUpdates" {
+            return self.updates(request)
+        }
+
+        let description = format!("MockConnector: no response for {:?}", request.url);
+        Some(response_body(json!({"ok": false, "error_code": 400, "description": description})))
+    }
+
+    fn updates(&self, request: &RecordedRequest) -> Option<HttpResponse> {
+        let offset = request.param("offset").and_then(Value::as_i64).unwrap_or(0);
+        let mut updates = self.inner.updates.borrow_mut();
+        if offset < 0 {
+            let skip = updates.len().saturating_sub(-offset as usize);
+            let result: Vec<_> = updates.iter().skip(skip).cloned().collect();
+            return Some(response_body(json!({"ok": true, "result": result})))
+        }
+        while updates.front().and_then(|update| update["update_id"].as_i64()).map_or(false, |id| id < offset) {
+            updates.pop_front();
+        }
+
+        if updates.is_empty() {
+            return None
+        }
+
+        let result: Vec<_> = updates.iter().cloned().collect();
+        Some(response_body(json!({"ok": true, "result": result})))
+    }
+}
+
+impl Default for MockConnector {
+    fn default() -> Self {
+        MockConnector::new()
+    }
+}
+
+impl fmt::Debug for MockConnector {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        "mock connector".fmt(formatter)
+    }
+}
+
+impl Connector for MockConnector {
+    fn request(&self, _base_url: &BaseUrl, _token: &str, req: HttpRequest) -> TelegramFuture<HttpResponse> {
+        let request = RecordedRequest::new(req);
+        self.inner.requests.borrow_mut().push(request.clone());
+
+        match self.response(&request) {
+            Some(response) => TelegramFuture::new(Box::new(result(Ok(response)))),
+            None => TelegramFuture::new(Box::new(empty())),
+        }
+    }
+}
+
+fn response_body(body: Value) -> HttpResponse {
+    HttpResponse {
+        body: Some(serde_json::to_vec(&body).unwrap()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::Stream;
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{GetMe, ChatId, MessageKind, UpdateKind, CanSendMessage};
+
+    use api::Api;
+    use super::*;
+
+    #[test]
+    fn record_requests() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+        mock.respond_error("sendMessage", 403, "Forbidden: bot was blocked by the user", None);
+
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        assert_eq!(core.run(api.send(GetMe)).unwrap().first_name, "Bot");
+        assert!(core.run(api.send(ChatId::new(2).text("Hello"))).is_err());
+        assert!(core.run(api.send(ChatId::new(2).text("Again"))).is_err());
+
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(mock.requests_to("sendMessage")[1].param("text"), Some(&json!("Again")));
+        mock.assert_message_sent(ChatId::new(2), "Hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "was not sent")]
+    fn assert_message_not_sent() {
+        MockConnector::new().assert_message_sent(ChatId::new(2), "Hello");
+    }
+
+    #[test]
+    fn feed_updates() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.push_text_message(UserId::new(2), "/start");
+        mock.push_text_message(UserId::new(2), "Hello");
+
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        let mut stream = api.stream();
+        stream.timeout(Duration::from_secs(0));
+        let updates = core.run(stream.take(2).collect()).unwrap();
+
+        let texts: Vec<_> = updates.into_iter().map(|update| match update.kind {
+            UpdateKind::Message(message) => match message.kind {
+                MessageKind::Text { data, .. } => data,
+                kind => panic!("unexpected message {:?}", kind),
+            },
+            kind => panic!("unexpected update {:?}", kind),
+        }).collect();
+        assert_eq!(texts, vec!["/start".to_string(), "Hello".to_string()]);
+    }
+}
@@ -2,12 +2,21 @@
 //!
 //! `CurlConnector` is default connector unless feature `curl_connector` is disabled and
 //! feature `hyper_connector` is enabled. This behaviour will change after hyper release.
+//!
+//! Default connectors send requests through the proxy configured with `HTTPS_PROXY`
+//! and `NO_PROXY` environment variables, see `Proxy::from_env`.
 
 mod _base;
 #[cfg(feature = "curl_connector")]
 pub mod curl;
 #[cfg(feature = "hyper_connector")]
 pub mod hyper;
+pub mod layer;
+#[cfg(any(test, feature = "testing"))]
+pub mod mock;
+pub mod proxy;
+#[cfg(feature = "hyper_connector")]
+mod tunnel;
 
 use tokio_core::reactor::Handle;
 
@@ -16,6 +25,10 @@ pub use self::_base::Connector;
 pub use self::curl::CurlConnector;
 #[cfg(feature = "hyper_connector")]
 pub use self::hyper::HyperConnector;
+pub use self::layer::{Layer, LoggingLayer, MetricsLayer, MapRequestLayer, ConnectorMetrics, MethodMetrics};
+#[cfg(any(test, feature = "testing"))]
+pub use self::mock::MockConnector;
+pub use self::proxy::{Proxy, ProxyProtocol};
 
 use errors::Error;
 
@@ -33,4 +46,20 @@ pub fn default_connector(handle: &Handle) -> Result<Box<Connector>, Error> {
 #[cfg(all(not(feature = "curl_connector"), all(feature = "hyper_connector")))]
 pub fn default_connector(handle: &Handle) -> Result<Box<Connector>, Error> {
     hyper::default_connector(handle)
-}
\ No newline at end of file
+}
+
+/// Returns default connector which sends requests through the `proxy`.
+///
+/// See module level documentation for details.
+#[cfg(feature = "curl_connector")]
+pub fn proxy_connector(handle: &Handle, proxy: Option<Proxy>) -> Result<Box<Connector>, Error> {
+    curl::proxy_connector(handle, proxy)
+}
+
+/// Returns default connector which sends requests through the `proxy`.
+///
+/// See module level documentation for details.
+#[cfg(all(not(feature = "curl_connector"), all(feature = "hyper_connector")))]
+pub fn proxy_connector(handle: &Handle, proxy: Option<Proxy>) -> Result<Box<Connector>, Error> {
+    hyper::proxy_connector(handle, proxy)
+}
@@ -0,0 +1,173 @@
+//! Tunnels through HTTP and SOCKS5 proxies for the hyper connector.
+
+use std::io;
+use std::rc::Rc;
+
+use base64;
+use futures::Future;
+use futures::future::{self, Loop, err, loop_fn};
+use hyper::Uri;
+use hyper::client::{HttpConnector, Service};
+use tokio_core::net::TcpStream;
+use tokio_core::reactor::Handle;
+use tokio_io::io::{read_exact, write_all};
+
+use super::proxy::{Proxy, ProxyProtocol};
+
+/// Maximal size of the proxy response to a `CONNECT` request.
+const MAX_RESPONSE_SIZE: usize = 8192;
+
+type Connecting = Box<Future<Item=TcpStream, Error=io::Error>>;
+
+/// Connects to hosts through a proxy, hosts bypassing the proxy are connected directly.
+pub struct TunnelConnector {
+    http: HttpConnector,
+    proxy: Option<Rc<Proxy>>,
+}
+
+impl TunnelConnector {
+    pub fn new(threads: usize, handle: &Handle, proxy: Option<Proxy>) -> Self {
+        let mut http = HttpConnector::new(threads, handle);
+        http.enforce_http(false);
+        TunnelConnector {
+            http: http,
+            proxy: proxy.map(Rc::new),
+        }
+    }
+}
+
+impl Service for TunnelConnector {
+    type Request = Uri;
+    type Response = TcpStream;
+    type Error = io::Error;
+    type Future = Connecting;
+
+    fn call(&self, uri: Uri) -> Self::Future {
+        let host = match uri.host() {
+            Some(host) => host.to_string(),
+            None => return Box::new(err(invalid_input("invalid url, missing host"))),
+        };
+
+        let proxy = match self.proxy {
+            Some(ref proxy) if !proxy.is_bypassed(&host) => proxy.clone(),
+            _ => return Box::new(self.http.call(uri)),
+        };
+
+        let port = uri.port().unwrap_or(if uri.scheme() == Some("https") { 443 } else { 80 });
+        let proxy_uri = match format!("http://{}", proxy.address()).parse() {
+            Ok(uri) => uri,
+            Err(_) => return Box::new(err(invalid_input("invalid proxy address"))),
+        };
+
+        Box::new(self.http.call(proxy_uri).and_then(move |stream| -> Connecting {
+            match proxy.protocol() {
+                ProxyProtocol::Http => http_connect(stream, &proxy, host, port),
+                ProxyProtocol::Socks5 => socks5_connect(stream, &proxy, host, port),
+            }
+        }))
+    }
+}
+
+/// Open a tunnel with the HTTP `CONNECT` request.
+fn http_connect(stream: TcpStream, proxy: &Proxy, host: String, port: u16) -> Connecting {
+    let address = if host.contains(':') { format!("[{}]:{}", host, port) } else { format!("{}:{}", host, port) };
+    let mut request = format!("CONNECT {0} HTTP/1.1\r\nHost: {0}\r\n", address);
+    if let Some((username, password)) = proxy.get_credentials() {
+        let credentials = base64::encode(format!("{}:{}", username, password).as_bytes());
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", credentials));
+    }
+    request.push_str("\r\n");
+
+    let response = write_all(stream, request.into_bytes()).and_then(|(stream, _)| {
+        loop_fn((stream, Vec::new()), |(stream, mut response)| {
+            read_exact(stream, [0u8]).and_then(move |(stream, byte)| {
+                response.push(byte[0]);
+                if response.ends_with(b"\r\n\r\n") {
+                    Ok(Loop::Break((stream, response)))
+                } else if response.len() > MAX_RESPONSE_SIZE {
+                    Err(proxy_error("response to CONNECT is too large"))
+                } else {
+                    Ok(Loop::Continue((stream, response)))
+                }
+            })
+        })
+    });
+
+    Box::new(response.and_then(|(stream, response)| {
+        let response = String::from_utf8_lossy(&response);
+        let status = response.split_whitespace().nth(1).unwrap_or("");
+        if status == "200" {
+            Ok(stream)
+        } else {
+            Err(proxy_error(&format!("CONNECT failed: {}", response.lines().next().unwrap_or(""))))
+        }
+    }))
+}
+
+/// Open a tunnel with the SOCKS5 `CONNECT` command, see RFC 1928 and RFC 1929.
+fn socks5_connect(stream: TcpStream, proxy: &Proxy, host: String, port: u16) -> Connecting {
+    if host.len() > 255 {
+        return Box::new(err(invalid_input("host name is too long")))
+    }
+    let credentials = proxy.get_credentials().map(|(username, password)| {
+        (username.as_bytes().to_vec(), password.as_bytes().to_vec())
+    });
+    if credentials.as_ref().map_or(false, |&(ref username, ref password)| username.len() > 255 || password.len() > 255) {
+        return Box::new(err(invalid_input("proxy credentials are too long")))
+    }
+
+    let greeting = if credentials.is_some() { vec![5, 2, 0, 2] } else { vec![5, 1, 0] };
+    let method = write_all(stream, greeting).and_then(|(stream, _)| read_exact(stream, [0u8; 2]));
+
+    let authenticated = method.and_then(move |(stream, reply)| -> Connecting {
+        match (reply, credentials) {
+            ([5, 0], _) => Box::new(future::ok(stream)),
+            ([5, 2], Some((username, password))) => {
+                let mut request = vec![1, username.len() as u8];
+                request.extend_from_slice(&username);
+                request.push(password.len() as u8);
+                request.extend_from_slice(&password);
+
+                Box::new(write_all(stream, request).and_then(|(stream, _)| read_exact(stream, [0u8; 2]))
+                    .and_then(|(stream, reply)| match reply {
+                        [1, 0] => Ok(stream),
+                        _ => Err(proxy_error("SOCKS5 authentication failed")),
+                    }))
+            }
+            _ => Box::new(err(proxy_error("SOCKS5 proxy rejected authentication methods"))),
+        }
+    });
+
+    let mut request = vec![5, 1, 0, 3, host.len() as u8];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&[(port >> 8) as u8, port as u8]);
+
+    let connected = authenticated
+        .and_then(|stream| write_all(stream, request))
+        .and_then(|(stream, _)| read_exact(stream, [0u8; 5]))
+        .and_then(|(stream, reply)| {
+            if reply[0] != 5 || reply[1] != 0 {
+                return Err(proxy_error(&format!("SOCKS5 connect failed with code {}", reply[1])))
+            }
+            // Skip the rest of the bound address and the port.
+            let remaining = match reply[3] {
+                1 => 4 - 1 + 2,
+                3 => reply[4] as usize + 2,
+                4 => 16 - 1 + 2,
+                _ => return Err(proxy_error("SOCKS5 reply has unknown address type")),
+            };
+            Ok((stream, remaining))
+        })
+        .and_then(|(stream, remaining)| read_exact(stream, vec![0u8; remaining]))
+        .map(|(stream, _)| stream);
+
+    Box::new(connected)
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn proxy_error(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, format!("proxy error: {}", message))
+}
@@ -0,0 +1,566 @@
+//! Multi-step conversations with a state kept per chat and user.
+//!
+//! A `Dialogue` loads the state of the sender of every incoming message or callback query
+//! from its `DialogueStorage`, passes both to the handler and stores the state returned by it.
+//! Handlers return `None` to finish the conversation, states without further updates
+//! can be expired after `DialogueConfig::timeout`.
+//!
+//! Updates of the same conversation must be dispatched one after another, e.g. with
+//! `Stream::for_each`: the handler receives the state stored before the previous update
+//! of the conversation is handled otherwise, and one of the transitions is lost.
+//!
+//! Expiry timers are kept in memory. States loaded by a storage from a previous run
+//! expire after the timeout counted from the moment the dialogue is built.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # extern crate futures;
+//! # extern crate telegram_bot;
+//! # extern crate tokio_core;
+//! use futures::{Future, IntoFuture, Stream};
+//! use futures::future::Either;
+//! use telegram_bot::{Api, CanReplySendMessage, MessageKind};
+//! use telegram_bot::dialogue::{Dialogue, DialogueUpdate, JsonFileStorage};
+//! use tokio_core::reactor::Core;
+//!
+//! # fn main() {
+//! let mut core = Core::new().unwrap();
+//! # let telegram_token = "token";
+//! let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+//!
+//! let dialogue = Dialogue::configure(|api, step: String, update| {
+//!     let message = match update {
+//!         DialogueUpdate::Message(message) => message,
+//!         DialogueUpdate::CallbackQuery(_) => return Either::A(Ok(Some(step)).into_future()),
+//!     };
+//!
+//!     let (reply, next) = match (step.as_str(), &message.kind) {
+//!         ("name", &MessageKind::Text { ref data, .. }) => (format!("Hello, {}!", data), None),
+//!         _ => ("What is your name?".to_string(), Some("name".to_string())),
+//!     };
+//!     Either::B(api.send(message.text_reply(reply)).map(move |_| next))
+//! })
+//!     .storage(JsonFileStorage::open("dialogues.json").unwrap())
+//!     .build(api.clone(), core.handle())
+//!     .unwrap();
+//!
+//! core.run(api.stream().for_each(|update| dialogue.dispatch(update))).unwrap();
+//! # }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+use futures::{Future, IntoFuture};
+use futures::future::{err, ok};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+use tokio_core::reactor::{Handle, Timeout};
+
+use telegram_bot_raw::{CallbackQuery, ChatId, Integer, Message, ToSourceChat, Update, UpdateKind, UserId};
+
+use api::Api;
+use errors::Error;
+use future::{TelegramFuture, NewTelegramFuture};
+
+/// Identifies a conversation with a user in a chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DialogueKey {
+    pub chat: ChatId,
+    pub user: UserId,
+}
+
+impl DialogueKey {
+    pub fn new<C: Into<ChatId>, U: Into<UserId>>(chat: C, user: U) -> Self {
+        DialogueKey {
+            chat: chat.into(),
+            user: user.into(),
+        }
+    }
+
+    fn to_storage_key(&self) -> String {
+        format!("{}:{}", self.chat, self.user)
+    }
+
+    fn from_storage_key(key: &str) -> Option<Self> {
+        let mut parts = key.splitn(2, ':');
+        let chat = parts.next()?.parse::<Integer>().ok()?;
+        let user = parts.next()?.parse::<Integer>().ok()?;
+        Some(DialogueKey::new(chat, user))
+    }
+}
+
+/// Update passed to the handler of a `Dialogue`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum DialogueUpdate {
+    Message(Message),
+    CallbackQuery(CallbackQuery),
+}
+
+impl DialogueUpdate {
+    /// Chat and sender of the update.
+    pub fn key(&self) -> DialogueKey {
+        match *self {
+            DialogueUpdate::Message(ref message) => DialogueKey::new(message.to_source_chat(), message.from.id),
+            DialogueUpdate::CallbackQuery(ref query) => DialogueKey::new(query.message.to_source_chat(), query.from.id),
+        }
+    }
+}
+
+/// Storage of dialogue states.
+pub trait DialogueStorage<S> {
+    /// State of the conversation, `None` if there is no conversation.
+    fn get(&self, key: &DialogueKey) -> Result<Option<S>, Error>;
+
+    /// Replace the state of the conversation.
+    fn set(&self, key: DialogueKey, state: S) -> Result<(), Error>;
+
+    /// Remove the state of the conversation and return it.
+    fn remove(&self, key: &DialogueKey) -> Result<Option<S>, Error>;
+
+    /// Keys of all stored conversations, used to expire states stored by a previous run.
+    /// The default implementation returns no keys, so such states never expire.
+    fn keys(&self) -> Result<Vec<DialogueKey>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// Keeps states in memory, they are lost when the process exits.
+#[derive(Debug)]
+pub struct MemoryStorage<S> {
+    states: RefCell<HashMap<DialogueKey, S>>,
+}
+
+impl<S> MemoryStorage<S> {
+    pub fn new() -> Self {
+        MemoryStorage {
+            states: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl<S> Default for MemoryStorage<S> {
+    fn default() -> Self {
+        MemoryStorage::new()
+    }
+}
+
+impl<S: Clone> DialogueStorage<S> for MemoryStorage<S> {
+    fn get(&self, key: &DialogueKey) -> Result<Option<S>, Error> {
+        Ok(self.states.borrow().get(key).cloned())
+    }
+
+    fn set(&self, key: DialogueKey, state: S) -> Result<(), Error> {
+        self.states.borrow_mut().insert(key, state);
+        Ok(())
+    }
+
+    fn remove(&self, key: &DialogueKey) -> Result<Option<S>, Error> {
+        Ok(self.states.borrow_mut().remove(key))
+    }
+
+    fn keys(&self) -> Result<Vec<DialogueKey>, Error> {
+        Ok(self.states.borrow().keys().cloned().collect())
+    }
+}
+
+/// Keeps states serialized to JSON in a file, which is rewritten on every change.
+#[derive(Debug)]
+pub struct JsonFileStorage {
+    path: PathBuf,
+    states: RefCell<HashMap<DialogueKey, Value>>,
+}
+
+impl JsonFileStorage {
+    /// Load states from the file at `path`, it is created on the first change if missing.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let states = match File::open(&path) {
+            Ok(file) => {
+                let states: HashMap<String, Value> = serde_json::from_reader(io::BufReader::new(file))?;
+                states.into_iter()
+                    .filter_map(|(key, state)| DialogueKey::from_storage_key(&key).map(|key| (key, state)))
+                    .collect()
+            }
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(JsonFileStorage {
+            path: path,
+            states: RefCell::new(states),
+        })
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        let states = self.states.borrow();
+        let states: HashMap<String, &Value> = states.iter()
+            .map(|(key, state)| (key.to_storage_key(), state)).collect();
+        let states = serde_json::to_vec(&states)?;
+
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, states)?;
+        fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+impl<S> DialogueStorage<S> for JsonFileStorage where S: Serialize + DeserializeOwned {
+    fn get(&self, key: &DialogueKey) -> Result<Option<S>, Error> {
+        match self.states.borrow().get(key) {
+            Some(state) => Ok(Some(serde_json::from_value(state.clone())?)),
+            None => Ok(None),
+        }
+    }
+
+    fn set(&self, key: DialogueKey, state: S) -> Result<(), Error> {
+        self.states.borrow_mut().insert(key, serde_json::to_value(state)?);
+        self.save()
+    }
+
+    fn remove(&self, key: &DialogueKey) -> Result<Option<S>, Error> {
+        let state = self.states.borrow_mut().remove(key);
+        match state {
+            Some(state) => {
+                self.save()?;
+                Ok(Some(serde_json::from_value(state)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn keys(&self) -> Result<Vec<DialogueKey>, Error> {
+        Ok(self.states.borrow().keys().cloned().collect())
+    }
+}
+
+type Handler<S> = Box<Fn(&Api, S, DialogueUpdate) -> TelegramFuture<Option<S>>>;
+
+type ExpireHandler<S> = Box<Fn(&Api, DialogueKey, S) -> TelegramFuture<()>>;
+
+/// Configuration of a `Dialogue`, created with `Dialogue::configure`.
+pub struct DialogueConfig<S> {
+    handler: Handler<S>,
+    storage: Box<DialogueStorage<S>>,
+    timeout: Option<Duration>,
+    on_expire: Option<ExpireHandler<S>>,
+}
+
+impl<S: 'static> DialogueConfig<S> {
+    /// Set storage of the states, `MemoryStorage` is used by default.
+    pub fn storage<T>(mut self, storage: T) -> Self where T: DialogueStorage<S> + 'static {
+        self.storage = Box::new(storage);
+        self
+    }
+
+    /// Remove states of conversations without updates for `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Call `handler` with the states removed after a timeout.
+    pub fn on_expire<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, DialogueKey, S) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.on_expire = Some(Box::new(move |api, key, state| {
+            TelegramFuture::new(Box::new(handler(api, key, state).into_future()))
+        }));
+        self
+    }
+
+    /// Create a dialogue sending requests with `api`, timeouts are run on `handle`.
+    ///
+    /// With a timeout, the expiry of the states already in the storage is scheduled.
+    pub fn build(self, api: Api, handle: Handle) -> Result<Dialogue<S>, Error> {
+        let inner = Rc::new(DialogueInner {
+            api: api,
+            handle: handle,
+            handler: self.handler,
+            storage: self.storage,
+            timeout: self.timeout,
+            on_expire: self.on_expire,
+            timers: RefCell::new(HashMap::new()),
+            generation: Cell::new(0),
+            handling: RefCell::new(HashMap::new()),
+        });
+
+        if inner.timeout.is_some() {
+            for key in inner.storage.keys()? {
+                schedule_expiry(&inner, key)?;
+            }
+        }
+
+        Ok(Dialogue {
+            inner: inner,
+        })
+    }
+}
+
+/// Routes messages and callback queries to a handler together with the state of
+/// the conversation with their sender, clones share the states.
+pub struct Dialogue<S> {
+    inner: Rc<DialogueInner<S>>,
+}
+
+struct DialogueInner<S> {
+    api: Api,
+    handle: Handle,
+    handler: Handler<S>,
+    storage: Box<DialogueStorage<S>>,
+    timeout: Option<Duration>,
+    on_expire: Option<ExpireHandler<S>>,
+    timers: RefCell<HashMap<DialogueKey, u64>>,
+    generation: Cell<u64>,
+    /// Number of updates being handled per conversation.
+    handling: RefCell<HashMap<DialogueKey, usize>>,
+}
+
+impl<S> Clone for Dialogue<S> {
+    fn clone(&self) -> Self {
+        Dialogue {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S: Clone + Default + 'static> Dialogue<S> {
+    /// Start configuring a dialogue, `handler` receives the current state, or the default one
+    /// for new conversations, and resolves to the next state.
+    pub fn configure<F, R>(handler: F) -> DialogueConfig<S>
+        where F: Fn(&Api, S, DialogueUpdate) -> R + 'static,
+              R: IntoFuture<Item=Option<S>, Error=Error>, R::Future: 'static
+    {
+        DialogueConfig {
+            handler: Box::new(move |api, state, update| {
+                TelegramFuture::new(Box::new(handler(api, state, update).into_future()))
+            }),
+            storage: Box::new(MemoryStorage::new()),
+            timeout: None,
+            on_expire: None,
+        }
+    }
+}
+
+impl<S: Default + 'static> Dialogue<S> {
+    /// Pass messages and callback queries to the handler, other updates are ignored.
+    pub fn dispatch(&self, update: Update) -> TelegramFuture<()> {
+        match update.kind {
+            UpdateKind::Message(message) => self.handle(DialogueUpdate::Message(message)),
+            UpdateKind::CallbackQuery(query) => self.handle(DialogueUpdate::CallbackQuery(query)),
+            _ => TelegramFuture::new(Box::new(ok(()))),
+        }
+    }
+
+    /// Pass the `update` to the handler and store the next state.
+    pub fn handle(&self, update: DialogueUpdate) -> TelegramFuture<()> {
+        let key = update.key();
+        let state = match self.inner.storage.get(&key) {
+            Ok(state) => state.unwrap_or_default(),
+            Err(error) => return TelegramFuture::new(Box::new(err(error))),
+        };
+
+        *self.inner.handling.borrow_mut().entry(key).or_insert(0) += 1;
+
+        let inner = self.inner.clone();
+        let future = (self.inner.handler)(&self.inner.api, state, update).then(move |next| {
+            finish_handling(&inner, &key);
+            transition(&inner, key, next?)
+        });
+
+        TelegramFuture::new(Box::new(future))
+    }
+
+    /// State of the conversation in the chat with the user.
+    pub fn state(&self, key: &DialogueKey) -> Result<Option<S>, Error> {
+        self.inner.storage.get(key)
+    }
+
+    /// Finish the conversation in the chat with the user.
+    pub fn reset(&self, key: &DialogueKey) -> Result<(), Error> {
+        self.inner.timers.borrow_mut().remove(key);
+        self.inner.storage.remove(key).map(|_| ())
+    }
+}
+
+fn transition<S: 'static>(inner: &Rc<DialogueInner<S>>, key: DialogueKey, next: Option<S>) -> Result<(), Error> {
+    match next {
+        Some(state) => {
+            inner.storage.set(key, state)?;
+            schedule_expiry(inner, key)
+        }
+        None => {
+            inner.timers.borrow_mut().remove(&key);
+            inner.storage.remove(&key).map(|_| ())
+        }
+    }
+}
+
+fn finish_handling<S>(inner: &DialogueInner<S>, key: &DialogueKey) {
+    let mut handling = inner.handling.borrow_mut();
+    let finished = match handling.get_mut(key) {
+        Some(count) => {
+            *count -= 1;
+            *count == 0
+        }
+        None => false,
+    };
+    if finished {
+        handling.remove(key);
+    }
+}
+
+fn schedule_expiry<S: 'static>(inner: &Rc<DialogueInner<S>>, key: DialogueKey) -> Result<(), Error> {
+    let timeout = match inner.timeout {
+        Some(timeout) => timeout,
+        None => return Ok(()),
+    };
+
+    let generation = inner.generation.get() + 1;
+    inner.generation.set(generation);
+    inner.timers.borrow_mut().insert(key, generation);
+
+    let expired = inner.clone();
+    let timer = Timeout::new(timeout, &inner.handle)?;
+    inner.handle.spawn(timer.map_err(Error::from).and_then(move |()| expire(&expired, key, generation)).then(|_| Ok::<(), ()>(())));
+    Ok(())
+}
+
+fn expire<S: 'static>(inner: &Rc<DialogueInner<S>>, key: DialogueKey, generation: u64) -> TelegramFuture<()> {
+    let current = inner.timers.borrow().get(&key) == Some(&generation);
+    if !current {
+        return TelegramFuture::new(Box::new(ok(())))
+    }
+
+    // The state stored by the running handler would revive the conversation,
+    // so the expiry is postponed until it finishes.
+    if inner.handling.borrow().contains_key(&key) {
+        return TelegramFuture::new(Box::new(schedule_expiry(inner, key).into_future()))
+    }
+    inner.timers.borrow_mut().remove(&key);
+
+    match (inner.storage.remove(&key), &inner.on_expire) {
+        (Ok(Some(state)), &Some(ref on_expire)) => on_expire(&inner.api, key, state),
+        (Err(error), _) => TelegramFuture::new(Box::new(err(error))),
+        _ => TelegramFuture::new(Box::new(ok(()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use futures::{future, Stream};
+    use tokio_core::reactor::{Core, Timeout};
+
+    use telegram_bot_raw::{CanReplySendMessage, CanSendMessage, MessageKind};
+
+    use connector::MockConnector;
+    use super::*;
+
+    /// Asks the name of the user and greets them.
+    fn handler(api: &Api, step: String, update: DialogueUpdate) -> TelegramFuture<Option<String>> {
+        let message = match update {
+            DialogueUpdate::Message(message) => message,
+            update => panic!("unexpected update {:?}", update),
+        };
+
+        let (reply, next) = match (step.as_str(), &message.kind) {
+            ("name", &MessageKind::Text { ref data, .. }) => (format!("Hello, {}!", data), None),
+            _ => ("What is your name?".to_string(), Some("name".to_string())),
+        };
+        TelegramFuture::new(Box::new(api.send(message.text_reply(reply)).map(move |_| next)))
+    }
+
+    fn respond_sent(mock: &MockConnector, count: usize) {
+        for _ in 0..count {
+            mock.respond("sendMessage", json!({
+                "message_id": 1, "date": 0, "text": "text",
+                "from": {"id": 3, "first_name": "Bot"},
+                "chat": {"id": 1, "type": "private", "first_name": "User"},
+            }));
+        }
+    }
+
+    fn key(chat: Integer, user: Integer) -> DialogueKey {
+        DialogueKey::new(ChatId::new(chat), UserId::new(user))
+    }
+
+    #[test]
+    fn conversation() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.push_text_message(UserId::new(1), "/register");
+        mock.push_text_message(UserId::new(2), "/register");
+        mock.push_text_message(UserId::new(1), "Alice");
+        respond_sent(&mock, 3);
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        let dialogue = Dialogue::configure(handler).build(api.clone(), core.handle()).unwrap();
+
+        core.run(api.stream().take(3).for_each(|update| dialogue.dispatch(update))).unwrap();
+
+        mock.assert_message_sent(ChatId::new(1), "Hello, Alice!");
+        assert_eq!(dialogue.state(&key(1, 1)).unwrap(), None);
+        assert_eq!(dialogue.state(&key(2, 2)).unwrap(), Some("name".to_string()));
+    }
+
+    #[test]
+    fn expire_state() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.push_text_message(UserId::new(1), "/register");
+        respond_sent(&mock, 2);
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        let dialogue = Dialogue::configure(handler)
+            .timeout(Duration::from_millis(10))
+            .on_expire(|api, key, _| api.send(key.chat.text("Registration expired")).map(|_| ()))
+            .build(api.clone(), core.handle()).unwrap();
+
+        core.run(api.stream().take(1).for_each(|update| dialogue.dispatch(update))).unwrap();
+        core.run(Timeout::new(Duration::from_millis(50), &core.handle()).unwrap()).unwrap();
+        core.run(future::ok::<(), ()>(())).unwrap();
+
+        assert_eq!(dialogue.state(&key(1, 1)).unwrap(), None);
+        mock.assert_message_sent(ChatId::new(1), "Registration expired");
+    }
+
+    #[test]
+    fn expire_stored_state() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        let storage = MemoryStorage::new();
+        storage.set(key(1, 1), "name".to_string()).unwrap();
+        let dialogue = Dialogue::configure(handler)
+            .storage(storage)
+            .timeout(Duration::from_millis(10))
+            .build(api.clone(), core.handle()).unwrap();
+
+        core.run(Timeout::new(Duration::from_millis(50), &core.handle()).unwrap()).unwrap();
+
+        assert_eq!(dialogue.state(&key(1, 1)).unwrap(), None);
+    }
+
+    #[test]
+    fn json_file_storage() {
+        let path = env::temp_dir().join(format!("telegram-bot-dialogue-{}.json", process::id()));
+        let key = key(-100, 1);
+
+        JsonFileStorage::open(&path).unwrap().set(key, vec![1, 2]).unwrap();
+        let storage = JsonFileStorage::open(&path).unwrap();
+        assert_eq!(storage.get(&key).unwrap(), Some(vec![1, 2]));
+        assert_eq!(storage.remove(&key).unwrap(), Some(vec![1, 2]));
+        assert_eq!(JsonFileStorage::open(&path).unwrap().get(&key).unwrap(), None::<Vec<i32>>);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,345 @@
+//! Routing of updates to handlers.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::IntoFuture;
+use futures::future::ok;
+
+use telegram_bot_raw::{CallbackQuery, ChannelPost, Message, MessageEntityKind, MessageKind};
+use telegram_bot_raw::{Update, UpdateKind, User};
+
+use api::Api;
+use errors::Error;
+use future::{TelegramFuture, NewTelegramFuture};
+
+/// Bot command at the start of a text message, like `/help@bot topic`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    /// Name of the command without the leading slash, e.g. `help`.
+    pub name: String,
+    /// Username of the bot the command is addressed to, e.g. `bot`.
+    pub username: Option<String>,
+    /// Arguments after the command, split by whitespace.
+    pub args: Vec<String>,
+    /// Text after the command with surrounding whitespace removed.
+    pub raw_args: String,
+}
+
+impl Command {
+    /// Parse the command marked with a `BotCommand` entity at the start of a text message.
+    pub fn parse(kind: &MessageKind) -> Option<Command> {
+        let (text, entities) = match *kind {
+            MessageKind::Text { ref data, ref entities } => (data, entities),
+            _ => return None,
+        };
+
+        let entity = entities.iter().find(|entity| {
+            entity.offset == 0 && entity.kind == MessageEntityKind::BotCommand
+        })?;
+
+        let (command, rest) = text.split_at(utf16_index(text, entity.length as usize));
+        if !command.starts_with('/') {
+            return None
+        }
+
+        let (name, username) = match command.find('@') {
+            Some(index) => (&command[1..index], Some(command[index + 1..].to_string())),
+            None => (&command[1..], None),
+        };
+        let raw_args = rest.trim();
+
+        Some(Command {
+            name: name.to_string(),
+            username: username,
+            args: raw_args.split_whitespace().map(ToString::to_string).collect(),
+            raw_args: raw_args.to_string(),
+        })
+    }
+
+    /// Returns `false` if the command is addressed to a bot other than `me` with the `@username` suffix.
+    pub fn is_addressed_to(&self, me: &User) -> bool {
+        match (&self.username, &me.username) {
+            (&None, _) => true,
+            (&Some(ref username), &Some(ref me)) => username.eq_ignore_ascii_case(me),
+            (&Some(_), &None) => false,
+        }
+    }
+}
+
+/// Byte index of `text` after `length` UTF-16 code units.
+fn utf16_index(text: &str, length: usize) -> usize {
+    let mut units = 0;
+    for (index, ch) in text.char_indices() {
+        if units >= length {
+            return index
+        }
+        units += ch.len_utf16();
+    }
+    text.len()
+}
+
+type CommandHandler = Box<Fn(&Api, Message, Command) -> TelegramFuture<()>>;
+
+type Handler<T> = Box<Fn(&Api, T) -> TelegramFuture<()>>;
+
+/// Routes updates to the handlers registered for commands and kinds of updates.
+///
+/// Commands addressed to other bots with the `@username` suffix are ignored,
+/// updates without a matching handler are dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # extern crate futures;
+/// # extern crate telegram_bot;
+/// # extern crate tokio_core;
+/// use futures::{Future, Stream};
+/// use telegram_bot::{Api, Dispatcher, GetMe, CanReplySendMessage};
+/// use tokio_core::reactor::Core;
+///
+/// # fn main() {
+/// let mut core = Core::new().unwrap();
+/// # let telegram_token = "token";
+/// let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+/// let me = core.run(api.send(GetMe)).unwrap();
+///
+/// let dispatcher = Dispatcher::new(api.clone(), &me)
+///     .command("start", |api, message, _| {
+///         api.send(message.text_reply("Hello!")).map(|_| ())
+///     })
+///     .command("echo", |api, message, command| {
+///         api.send(message.text_reply(command.raw_args)).map(|_| ())
+///     })
+///     .unknown_command(|api, message, command| {
+///         api.send(message.text_reply(format!("Unknown command /{}", command.name))).map(|_| ())
+///     });
+///
+/// core.run(api.stream().for_each(|update| dispatcher.dispatch(update))).unwrap();
+/// # }
+/// ```
+pub struct Dispatcher {
+    api: Api,
+    me: User,
+    commands: HashMap<String, CommandHandler>,
+    unknown_command: Option<CommandHandler>,
+    message: Option<Handler<Message>>,
+    edited_message: Option<Handler<Message>>,
+    channel_post: Option<Handler<ChannelPost>>,
+    edited_channel_post: Option<Handler<ChannelPost>>,
+    callback_query: Option<Handler<CallbackQuery>>,
+}
+
+impl Dispatcher {
+    /// Create a dispatcher for the bot `me`, returned by the `GetMe` request.
+    pub fn new(api: Api, me: &User) -> Self {
+        Dispatcher {
+            api: api,
+            me: me.clone(),
+            commands: HashMap::new(),
+            unknown_command: None,
+            message: None,
+            edited_message: None,
+            channel_post: None,
+            edited_channel_post: None,
+            callback_query: None,
+        }
+    }
+
+    /// Handle messages starting with the command `/name`.
+    pub fn command<F, R>(mut self, name: &str, handler: F) -> Self
+        where F: Fn(&Api, Message, Command) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.commands.insert(name.to_string(), command_handler(handler));
+        self
+    }
+
+    /// Handle commands without a registered handler.
+    pub fn unknown_command<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, Message, Command) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.unknown_command = Some(command_handler(handler));
+        self
+    }
+
+    /// Handle messages which are not commands.
+    pub fn message<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, Message) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.message = Some(update_handler(handler));
+        self
+    }
+
+    /// Handle edited messages.
+    pub fn edited_message<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, Message) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.edited_message = Some(update_handler(handler));
+        self
+    }
+
+    /// Handle channel posts.
+    pub fn channel_post<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, ChannelPost) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.channel_post = Some(update_handler(handler));
+        self
+    }
+
+    /// Handle edited channel posts.
+    pub fn edited_channel_post<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, ChannelPost) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.edited_channel_post = Some(update_handler(handler));
+        self
+    }
+
+    /// Handle callback queries from inline keyboards.
+    pub fn callback_query<F, R>(mut self, handler: F) -> Self
+        where F: Fn(&Api, CallbackQuery) -> R + 'static,
+              R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+    {
+        self.callback_query = Some(update_handler(handler));
+        self
+    }
+
+    /// Pass the `update` to its handler, resolves when the handler finishes.
+    pub fn dispatch(&self, update: Update) -> TelegramFuture<()> {
+        match update.kind {
+            UpdateKind::Message(message) => self.dispatch_message(message),
+            UpdateKind::EditedMessage(message) => self.call(&self.edited_message, message),
+            UpdateKind::ChannelPost(post) => self.call(&self.channel_post, post),
+            UpdateKind::EditedChannelPost(post) => self.call(&self.edited_channel_post, post),
+            UpdateKind::CallbackQuery(query) => self.call(&self.callback_query, query),
+            _ => done(),
+        }
+    }
+
+    fn dispatch_message(&self, message: Message) -> TelegramFuture<()> {
+        let command = match Command::parse(&message.kind) {
+            Some(command) => command,
+            None => return self.call(&self.message, message),
+        };
+
+        if !command.is_addressed_to(&self.me) {
+            return done()
+        }
+
+        match self.commands.get(&command.name).or(self.unknown_command.as_ref()) {
+            Some(handler) => handler(&self.api, message, command),
+            None => done(),
+        }
+    }
+
+    fn call<T>(&self, handler: &Option<Handler<T>>, value: T) -> TelegramFuture<()> {
+        match *handler {
+            Some(ref handler) => handler(&self.api, value),
+            None => done(),
+        }
+    }
+}
+
+impl fmt::Debug for Dispatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut commands: Vec<_> = self.commands.keys().collect();
+        commands.sort();
+        f.debug_struct("Dispatcher")
+            .field("username", &self.me.username)
+            .field("commands", &commands)
+            .finish()
+    }
+}
+
+fn command_handler<F, R>(handler: F) -> CommandHandler
+    where F: Fn(&Api, Message, Command) -> R + 'static,
+          R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+{
+    Box::new(move |api, message, command| {
+        TelegramFuture::new(Box::new(handler(api, message, command).into_future()))
+    })
+}
+
+fn update_handler<T, F, R>(handler: F) -> Handler<T>
+    where F: Fn(&Api, T) -> R + 'static,
+          R: IntoFuture<Item=(), Error=Error>, R::Future: 'static
+{
+    Box::new(move |api, value| TelegramFuture::new(Box::new(handler(api, value).into_future())))
+}
+
+fn done() -> TelegramFuture<()> {
+    TelegramFuture::new(Box::new(ok(())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures::{Future, Stream};
+    use serde_json;
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{UserId, CanReplySendMessage};
+
+    use connector::MockConnector;
+    use super::*;
+
+    fn command(text: &str, length: usize) -> Option<Command> {
+        Command::parse(&MessageKind::Text {
+            data: text.to_string(),
+            entities: vec![serde_json::from_value(json!({"type": "bot_command", "offset": 0, "length": length})).unwrap()],
+        })
+    }
+
+    #[test]
+    fn parse_command() {
+        assert_eq!(command("/help@Bot  a\tb ", 9), Some(Command {
+            name: "help".to_string(),
+            username: Some("Bot".to_string()),
+            args: vec!["a".to_string(), "b".to_string()],
+            raw_args: "a\tb".to_string(),
+        }));
+        assert_eq!(command("/😀 arg", 3).map(|command| command.name), Some("😀".to_string()));
+        assert_eq!(command("help", 4), None);
+    }
+
+    #[test]
+    fn dispatch_updates() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        for text in &["/start", "/echo@Bot some text", "/start@other_bot", "/unknown", "hello"] {
+            mock.push_text_message(UserId::new(1), text);
+        }
+        mock.push_update(json!({"edited_message": {
+            "message_id": 1, "date": 0, "text": "edited",
+            "from": {"id": 1, "first_name": "User"},
+            "chat": {"id": 1, "type": "private", "first_name": "User"},
+        }}));
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        let me: User = serde_json::from_value(json!({"id": 2, "first_name": "Bot", "username": "bot"})).unwrap();
+
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let (start, echo, unknown, message, edited) =
+            (calls.clone(), calls.clone(), calls.clone(), calls.clone(), calls.clone());
+        let dispatcher = Dispatcher::new(api.clone(), &me)
+            .command("start", move |_, _, _| Ok(start.borrow_mut().push("start".to_string())))
+            .command("echo", move |api, message, command| {
+                echo.borrow_mut().push(command.raw_args.clone());
+                api.send(message.text_reply(command.raw_args)).then(|_| Ok(()))
+            })
+            .unknown_command(move |_, _, command| Ok(unknown.borrow_mut().push(command.name)))
+            .message(move |_, _| Ok(message.borrow_mut().push("message".to_string())))
+            .edited_message(move |_, _| Ok(edited.borrow_mut().push("edited".to_string())));
+
+        core.run(api.stream().take(6).for_each(|update| dispatcher.dispatch(update))).unwrap();
+
+        assert_eq!(*calls.borrow(), vec!["start", "some text", "unknown", "message", "edited"]);
+        assert_eq!(mock.requests_to("sendMessage")[0].param("text"), Some(&json!("some text")));
+    }
+}
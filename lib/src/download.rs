@@ -0,0 +1,123 @@
+//! Downloading files from the Telegram server.
+
+use futures::{Future, Stream, Poll};
+
+use telegram_bot_raw::{GetFile, ToFileRef};
+
+use api::Api;
+use errors::Error;
+use future::{TelegramFuture, NewTelegramFuture};
+
+/// Maximal size of a file which can be downloaded by a bot from the official Bot API server.
+pub const MAX_DOWNLOAD_SIZE: i64 = 20 * 1024 * 1024;
+
+/// Represent a stream of file contents, produced in chunks.
+#[must_use = "streams do nothing unless polled"]
+pub struct FileStream {
+    inner: Box<Stream<Item=Vec<u8>, Error=Error>>
+}
+
+pub trait NewFileStream {
+    fn new(inner: Box<Stream<Item=Vec<u8>, Error=Error>>) -> Self;
+}
+
+impl NewFileStream for FileStream {
+    fn new(inner: Box<Stream<Item=Vec<u8>, Error=Error>>) -> Self {
+        Self {
+            inner: inner
+        }
+    }
+}
+
+impl Stream for FileStream {
+    type Item = Vec<u8>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/// Get info about a file and download it in one step.
+pub trait CanDownloadFile {
+    fn download(&self, api: &Api) -> TelegramFuture<Vec<u8>>;
+}
+
+impl<F> CanDownloadFile for F where F: ToFileRef {
+    fn download(&self, api: &Api) -> TelegramFuture<Vec<u8>> {
+        let api = api.clone();
+        let future = api.send(GetFile::new(self)).and_then(move |file| api.download_file(&file));
+        TelegramFuture::new(Box::new(future))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::File;
+
+    use connector::MockConnector;
+    use errors::ErrorKind;
+    use super::*;
+
+    fn file(file_size: Option<i64>) -> File {
+        File {
+            file_id: "file_id".to_string(),
+            file_size: file_size,
+            file_path: Some("documents/file.bin".to_string()),
+        }
+    }
+
+    #[test]
+    fn download_file_to() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond_download(b"contents".to_vec());
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let path = env::temp_dir().join(format!("telegram-bot-download-{}", process::id()));
+        core.run(api.download_file_to(&file(Some(8)), &path)).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"contents");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn download_file_to_removes_partial_file() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond_download(vec![0; MAX_DOWNLOAD_SIZE as usize + 1]);
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let path = env::temp_dir().join(format!("telegram-bot-partial-{}", process::id()));
+        assert!(core.run(api.download_file_to(&file(None), &path)).is_err());
+
+        assert!(!path.exists());
+        assert!(!path.with_file_name(format!("telegram-bot-partial-{}.part", process::id())).exists());
+    }
+
+    #[test]
+    fn download_limit() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let data = vec![0; MAX_DOWNLOAD_SIZE as usize + 1];
+
+        mock.respond_download(data.clone());
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+        match core.run(api.download_file(&file(None))) {
+            Err(Error(ErrorKind::FileTooLarge(size), _)) => assert_eq!(size, MAX_DOWNLOAD_SIZE + 1),
+            result => panic!("unexpected result {:?}", result.map(|data| data.len())),
+        }
+
+        mock.respond_download(data.clone());
+        let api = Api::configure("token").connector(mock.boxed())
+            .base_url("http://localhost:8081/").build(core.handle()).unwrap();
+        let size = Some(MAX_DOWNLOAD_SIZE + 1);
+        assert_eq!(core.run(api.download_file(&file(size))).unwrap().len(), data.len());
+    }
+}
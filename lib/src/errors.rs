@@ -3,13 +3,36 @@ use telegram_bot_raw;
 error_chain! {
     foreign_links {
         Url(::hyper::error::UriError) #[cfg(feature = "hyper_connector")];
-        Hyper(::hyper::Error) #[cfg(feature = "hyper_connector")];
+        Hyper(::hyper::Error) #[cfg(any(feature = "hyper_connector", feature = "webhook"))];
         Curl(::curl::Error) #[cfg(feature = "curl_connector")];
+        CurlForm(::curl::FormError) #[cfg(feature = "curl_connector")];
         CurlPerformError(::tokio_curl::PerformError) #[cfg(feature = "curl_connector")];
         Io(::std::io::Error);
+        Json(::serde_json::Error);
     }
 
     links {
         Raw(telegram_bot_raw::Error, telegram_bot_raw::ErrorKind);
     }
+
+    errors {
+        FileTooLarge(size: i64) {
+            description("file is too big to download")
+            display("file is too big to download: {} bytes", size)
+        }
+        FileUnavailable {
+            description("file is not available for download")
+        }
+        ApiShutdown {
+            description("api is no longer running")
+        }
+        InvalidProxy(url: String) {
+            description("invalid proxy url")
+            display("invalid proxy url: {}", url)
+        }
+        DownloadFailed(status: u16) {
+            description("file download failed")
+            display("file download failed with HTTP status {}", status)
+        }
+    }
 }
@@ -5,6 +5,12 @@ extern crate antidote;
 #[macro_use]
 extern crate error_chain;
 extern crate futures;
+#[macro_use]
+extern crate log;
+extern crate percent_encoding;
+extern crate serde;
+#[cfg_attr(any(test, feature = "testing"), macro_use)]
+extern crate serde_json;
 extern crate tokio_core;
 extern crate telegram_bot_raw;
 
@@ -13,25 +19,60 @@ extern crate curl;
 #[cfg(feature = "curl_connector")]
 extern crate tokio_curl;
 
-#[cfg(feature = "hyper_connector")]
+#[cfg(any(feature = "hyper_connector", feature = "webhook"))]
 extern crate hyper;
 #[cfg(feature = "hyper_connector")]
 extern crate hyper_tls;
+#[cfg(feature = "hyper_connector")]
+extern crate native_tls;
+#[cfg(feature = "hyper_connector")]
+extern crate tokio_io;
+#[cfg(feature = "hyper_connector")]
+extern crate base64;
+
+#[cfg(feature = "derive")]
+extern crate telegram_bot_derive;
 
 mod api;
+mod dispatcher;
+mod download;
 mod errors;
 mod future;
 mod macros;
+mod migration;
+mod offset;
+mod rate_limit;
+mod retry;
+mod set_webhook;
 mod stream;
+mod sync_api;
+#[cfg(feature = "webhook")]
+mod webhook;
 
+pub mod blocking;
+pub mod commands;
+pub mod compat;
 pub mod connector;
+pub mod dialogue;
 pub mod prelude;
 pub mod types;
 
 pub use self::api::{Api, Config};
+pub use commands::{BotCommands, CommandError};
 pub use connector::*;
+pub use dispatcher::{Command, Dispatcher};
+#[cfg(feature = "derive")]
+pub use telegram_bot_derive::BotCommands;
+pub use download::FileStream;
 pub use self::errors::{Error, ErrorKind};
 pub use self::future::TelegramFuture;
-pub use stream::UpdatesStream;
+pub use migration::ChatMigration;
+pub use offset::{OffsetStore, MemoryOffsetStore, FileOffsetStore};
+pub use rate_limit::{Rate, RateLimits, RateLimitMetrics};
+pub use retry::{RetryPolicy, Throttling};
+pub use stream::{UpdatesStream, UpdateAcks};
+pub use sync_api::{SyncApi, SyncFuture};
+#[cfg(feature = "webhook")]
+pub use webhook::WebhookServer;
 pub use prelude::*;
 pub use types::*;
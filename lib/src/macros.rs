@@ -18,9 +18,16 @@
 ///
 /// let inline_keyboard = reply_markup!(inline_keyboard,
 ///     ["button" callback "0,0", "button" callback "0,1"],
-///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"]
+///     ["button" callback "1,0", "button" callback "1,1", "button" callback "1,2"],
+///     ["button" url "https://example.com", "button" login_url "https://example.com/login"],
+///     ["button" switch_inline_query "query", "button" switch_inline_query_current_chat "query"]
 /// );
 ///
+/// let game_keyboard = reply_markup!(inline_keyboard, ["button" callback_game]);
+/// let pay_keyboard = reply_markup!(inline_keyboard, ["button" pay]);
+///
+/// # drop(pay_keyboard);
+/// # drop(game_keyboard);
 /// # drop(inline_keyboard);
 /// # drop(selective_force_reply);
 /// # drop(force_reply);
@@ -108,17 +115,42 @@ macro_rules! reply_markup {
         $crate::InlineKeyboardMarkup::from(vec![$(reply_markup![_inline_keyboard_row, $($content)*]), *])
     );
 
-    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $callback:tt) => (
-        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request,  $text, $callback)]
+    (_inline_keyboard_row, ($($acc:tt)*);) => (vec![$($acc)*]);
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $data:tt, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, $request, $text, $data),); $($remaining)*)
+    );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt $data:tt) => (
+        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request, $text, $data)]
+    );
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt, $($remaining:tt)*) => (
+        reply_markup!(_inline_keyboard_row, ($($acc)* reply_markup!(_inline_keyboard_button, $request, $text),); $($remaining)*)
     );
-    (_inline_keyboard_row, $($text:tt $request:tt $callback:tt), *) => (
-        vec![$(reply_markup!(_inline_keyboard_button, $request, $text, $callback)), *]
+    (_inline_keyboard_row, ($($acc:tt)*); $text:tt $request:tt) => (
+        vec![$($acc)* reply_markup!(_inline_keyboard_button, $request, $text)]
     );
     (_inline_keyboard_row, $($content:tt)*) => (reply_markup!(_inline_keyboard_row, (); $($content)*));
 
     (_inline_keyboard_button, callback, $text:expr, $callback:expr) => (
         $crate::InlineKeyboardButton::callback($text, $callback)
     );
+    (_inline_keyboard_button, url, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::url($text, $url)
+    );
+    (_inline_keyboard_button, switch_inline_query, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query($text, $query)
+    );
+    (_inline_keyboard_button, switch_inline_query_current_chat, $text:expr, $query:expr) => (
+        $crate::InlineKeyboardButton::switch_inline_query_current_chat($text, $query)
+    );
+    (_inline_keyboard_button, login_url, $text:expr, $url:expr) => (
+        $crate::InlineKeyboardButton::login_url($text, $url)
+    );
+    (_inline_keyboard_button, callback_game, $text:expr) => (
+        $crate::InlineKeyboardButton::callback_game($text)
+    );
+    (_inline_keyboard_button, pay, $text:expr) => (
+        $crate::InlineKeyboardButton::pay($text)
+    );
 }
 
 #[cfg(test)]
@@ -223,5 +255,28 @@ mod tests {
             row.push(InlineKeyboardButton::callback("baz", "quux"));
         }
         assert_eq!(markup, reply_markup!(inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"]));
+
+        {
+            let row = markup.add_empty_row();
+            row.push(InlineKeyboardButton::url("url", "https://example.com"));
+            row.push(InlineKeyboardButton::pay("pay"));
+            row.push(InlineKeyboardButton::switch_inline_query("switch", "query"));
+            row.push(InlineKeyboardButton::callback_game("game"));
+        }
+        assert_eq!(markup, reply_markup!(
+            inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"],
+            ["url" url "https://example.com", "pay" pay, "switch" switch_inline_query "query", "game" callback_game]
+        ));
+
+        {
+            let row = markup.add_empty_row();
+            row.push(InlineKeyboardButton::switch_inline_query_current_chat("switch", ""));
+            row.push(InlineKeyboardButton::login_url("login", "https://example.com/login"));
+        }
+        assert_eq!(markup, reply_markup!(
+            inline_keyboard, [], ["foo" callback "bar", "baz" callback "quux"],
+            ["url" url "https://example.com", "pay" pay, "switch" switch_inline_query "query", "game" callback_game],
+            ["switch" switch_inline_query_current_chat "", "login" login_url "https://example.com/login"]
+        ));
     }
 }
@@ -0,0 +1,138 @@
+//! Following group to supergroup migrations.
+
+use std::fmt;
+use std::rc::Rc;
+
+use telegram_bot_raw::{self, ChatRef, ResponseParameters, SupergroupId};
+
+use errors::{Error, ErrorKind};
+
+/// Information about a group upgraded to a supergroup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatMigration {
+    /// Chat the request was sent to.
+    pub from: ChatRef,
+    /// Supergroup the request was sent to again.
+    pub to: SupergroupId,
+}
+
+/// Returns the new supergroup if the request failed because the group was migrated.
+pub fn migrated_to(error: &Error) -> Option<SupergroupId> {
+    match *error.kind() {
+        ErrorKind::Raw(telegram_bot_raw::ErrorKind::TelegramError {
+            parameters: Some(ResponseParameters { migrate_to_chat_id: Some(id), .. }), ..
+        }) => Some(SupergroupId::new(id)),
+        _ => None,
+    }
+}
+
+/// Callback to notify about followed migrations.
+#[derive(Clone)]
+pub struct MigrationHook {
+    hook: Rc<Fn(&ChatMigration)>,
+}
+
+impl MigrationHook {
+    pub fn new<F>(hook: F) -> Self where F: Fn(&ChatMigration) + 'static {
+        MigrationHook {
+            hook: Rc::new(hook),
+        }
+    }
+
+    pub fn call(&self, migration: &ChatMigration) {
+        (self.hook)(migration)
+    }
+}
+
+impl fmt::Debug for MigrationHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("MigrationHook")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{GroupId, ToChatRef};
+    use telegram_bot_raw::CanSendMessage;
+
+    use api::Api;
+    use connector::MockConnector;
+    use super::*;
+
+    fn migrated(mock: &MockConnector) {
+        mock.respond_error("sendMessage", 400, "Bad Request: group chat was upgraded to a supergroup chat",
+                           Some(json!({"migrate_to_chat_id": -1001113717682i64})));
+    }
+
+    fn sent(mock: &MockConnector) {
+        mock.respond("sendMessage", json!({
+            "message_id": 1, "date": 0, "text": "text",
+            "from": {"id": 1, "first_name": "Bot"},
+            "chat": {"id": -1001113717682i64, "type": "supergroup", "title": "Group"}
+        }));
+    }
+
+    fn api(core: &Core, mock: &MockConnector, follow: bool) -> (Api, Rc<RefCell<Vec<ChatMigration>>>) {
+        let migrations = Rc::new(RefCell::new(Vec::new()));
+
+        let mut config = Api::configure("token").connector(mock.boxed());
+        if follow {
+            let hook_migrations = migrations.clone();
+            config = config.follow_chat_migrations(move |migration| {
+                hook_migrations.borrow_mut().push(migration.clone())
+            });
+        }
+
+        (config.build(core.handle()).unwrap(), migrations)
+    }
+
+    #[test]
+    fn follow_migration() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        migrated(&mock);
+        sent(&mock);
+        let (api, migrations) = api(&core, &mock, true);
+
+        let message = core.run(api.send(GroupId::new(-216055857).text("text"))).unwrap();
+        assert_eq!(message.chat.id(), SupergroupId::new(-1001113717682).into());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].request.chat_ref(), Some(SupergroupId::new(-1001113717682).to_chat_ref()));
+        assert_eq!(*migrations.borrow(), vec![ChatMigration {
+            from: GroupId::new(-216055857).to_chat_ref(),
+            to: SupergroupId::new(-1001113717682),
+        }]);
+    }
+
+    #[test]
+    fn follow_migration_once() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        migrated(&mock);
+        migrated(&mock);
+        let (api, migrations) = api(&core, &mock, true);
+
+        assert!(core.run(api.send(GroupId::new(-216055857).text("text"))).is_err());
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(migrations.borrow().len(), 1);
+    }
+
+    #[test]
+    fn no_migration_by_default() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        migrated(&mock);
+        sent(&mock);
+        let (api, _) = api(&core, &mock, false);
+
+        assert!(core.run(api.send(GroupId::new(-216055857).text("text"))).is_err());
+        assert_eq!(mock.requests().len(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use std::cell::Cell;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use telegram_bot_raw::Integer;
+
+use errors::Error;
+
+/// Storage of the id of the last processed update, used by `UpdatesStream::with_offset_store`
+/// to continue from it after a restart.
+pub trait OffsetStore {
+    /// Id of the last processed update, `None` if nothing was saved yet.
+    fn load(&self) -> Result<Option<Integer>, Error>;
+
+    /// Save the id of the last processed update.
+    fn save(&self, update_id: Integer) -> Result<(), Error>;
+}
+
+/// Keeps the offset in memory, clones share the offset.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryOffsetStore {
+    offset: Rc<Cell<Option<Integer>>>,
+}
+
+impl MemoryOffsetStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Id of the last processed update.
+    pub fn offset(&self) -> Option<Integer> {
+        self.offset.get()
+    }
+}
+
+impl OffsetStore for MemoryOffsetStore {
+    fn load(&self) -> Result<Option<Integer>, Error> {
+        Ok(self.offset.get())
+    }
+
+    fn save(&self, update_id: Integer) -> Result<(), Error> {
+        self.offset.set(Some(update_id));
+        Ok(())
+    }
+}
+
+/// Keeps the offset as text in a file, which is replaced on every save.
+#[derive(Debug, Clone)]
+pub struct FileOffsetStore {
+    path: PathBuf,
+}
+
+impl FileOffsetStore {
+    /// Store the offset in the file at `path`, it is created on the first save if missing.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileOffsetStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl OffsetStore for FileOffsetStore {
+    fn load(&self) -> Result<Option<Integer>, Error> {
+        let mut content = String::new();
+        match File::open(&self.path) {
+            Ok(mut file) => file.read_to_string(&mut content)?,
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        content.trim().parse().map(Some).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("invalid update offset {:?}", content)).into()
+        })
+    }
+
+    fn save(&self, update_id: Integer) -> Result<(), Error> {
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, update_id.to_string())?;
+        fs::rename(&temporary, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn file_offset_store() {
+        let path = env::temp_dir().join(format!("telegram-bot-offset-{}", process::id()));
+        let store = FileOffsetStore::new(&path);
+
+        assert_eq!(store.load().unwrap(), None);
+        store.save(42).unwrap();
+        assert_eq!(FileOffsetStore::new(&path).load().unwrap(), Some(42));
+
+        fs::write(&path, "garbage").unwrap();
+        assert!(store.load().is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -3,20 +3,34 @@
 //! This module re-exports request builder traits from telegram-bot-raw.
 
 pub use telegram_bot_raw::{ToReplyRequest, ToRequest};
-pub use telegram_bot_raw::CanAnswerCallbackQuery;
+pub use telegram_bot_raw::{CanAnswerCallbackQuery, CanAnswerInlineQuery};
 pub use telegram_bot_raw::{CanReplySendContact, CanSendContact};
 pub use telegram_bot_raw::{CanReplySendLocation, CanSendLocation};
 pub use telegram_bot_raw::{CanReplySendMessage, CanSendMessage};
 pub use telegram_bot_raw::{CanReplySendVenue, CanSendVenue};
 pub use telegram_bot_raw::{CanReplySendAudio, CanSendAudio};
+pub use telegram_bot_raw::{CanReplySendAnimation, CanSendAnimation};
+pub use telegram_bot_raw::{CanReplySendDocument, CanSendDocument};
+pub use telegram_bot_raw::{CanReplySendPhoto, CanSendPhoto};
+pub use telegram_bot_raw::{CanReplySendSticker, CanSendSticker};
+pub use telegram_bot_raw::{CanReplySendVideo, CanSendVideo};
+pub use telegram_bot_raw::{CanReplySendVideoNote, CanSendVideoNote};
+pub use telegram_bot_raw::{CanReplySendVoice, CanSendVoice};
+pub use telegram_bot_raw::{CanReplySendMediaGroup, CanSendMediaGroup};
 pub use telegram_bot_raw::{CanDeleteMessage, CanForwardMessage};
 pub use telegram_bot_raw::CanSendChatAction;
 pub use telegram_bot_raw::{CanGetChat, CanGetChatAdministrators, CanGetChatMembersCount};
 pub use telegram_bot_raw::{CanGetChatMemberForChat, CanGetChatMemberForUser};
 pub use telegram_bot_raw::{CanKickChatMemberForChat, CanKickChatMemberForUser};
 pub use telegram_bot_raw::{CanUnbanChatMemberForChat, CanUnbanChatMemberForUser};
+pub use telegram_bot_raw::{CanRestrictChatMemberForChat, CanRestrictChatMemberForUser};
+pub use telegram_bot_raw::{CanPromoteChatMemberForChat, CanPromoteChatMemberForUser};
 pub use telegram_bot_raw::CanLeaveChat;
+pub use telegram_bot_raw::{CanSetChatTitle, CanSetChatDescription, CanSetChatPhoto, CanDeleteChatPhoto};
+pub use telegram_bot_raw::{CanExportChatInviteLink, CanSetChatStickerSet, CanDeleteChatStickerSet};
 pub use telegram_bot_raw::{CanEditMessageCaption, CanEditMessageReplyMarkup, CanEditMessageText};
 pub use telegram_bot_raw::{CanGetFile, CanGetUserProfilePhotos};
+pub use download::CanDownloadFile;
+pub use set_webhook::{CanSetWebhook, CanDeleteWebhook, CanGetWebhookInfo};
 pub use telegram_bot_raw::{CanPinMessage, CanUnpinMessage};
 pub use telegram_bot_raw::{CanEditMessageLiveLocation, CanStopMessageLiveLocation};
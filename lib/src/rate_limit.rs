@@ -0,0 +1,333 @@
+//! Client-side rate limiting of outgoing requests.
+
+use std::cell::RefCell;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use telegram_bot_raw::{ChatRef, Integer};
+
+/// Number of per-chat buckets kept before idle ones are dropped.
+const MAX_IDLE_BUCKETS: usize = 1024;
+
+/// Allowed amount of requests per period of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rate {
+    amount: u32,
+    period: Duration,
+}
+
+impl Rate {
+    /// Allow `amount` requests per `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is zero.
+    pub fn new(amount: u32, period: Duration) -> Self {
+        assert!(amount > 0, "rate amount must be positive");
+        Rate {
+            amount: amount,
+            period: period,
+        }
+    }
+
+    /// Allow `amount` requests per second, panics if `amount` is zero.
+    pub fn per_second(amount: u32) -> Self {
+        Rate::new(amount, Duration::from_secs(1))
+    }
+
+    /// Allow `amount` requests per minute, panics if `amount` is zero.
+    pub fn per_minute(amount: u32) -> Self {
+        Rate::new(amount, Duration::from_secs(60))
+    }
+
+    /// Interval between two requests at a steady pace.
+    fn interval(&self) -> Duration {
+        self.period / self.amount
+    }
+
+    /// How far the pace can be exceeded, i.e. the size of a burst.
+    fn tolerance(&self) -> Duration {
+        self.period - self.interval()
+    }
+}
+
+/// Limits for requests sent to a chat. Requests without a `chat_id`
+/// parameter, like `getUpdates`, are never limited.
+///
+/// Defaults to the limits of the Telegram server: 30 requests per second
+/// in total, 1 request per second to a private chat and 20 requests
+/// per minute to a group, supergroup or channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimits {
+    global: Rate,
+    private_chat: Rate,
+    group_chat: Rate,
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        RateLimits {
+            global: Rate::per_second(30),
+            private_chat: Rate::per_second(1),
+            group_chat: Rate::per_minute(20),
+        }
+    }
+}
+
+impl RateLimits {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Limit of requests to all chats.
+    pub fn global(self, rate: Rate) -> Self {
+        RateLimits { global: rate, ..self }
+    }
+
+    /// Limit of requests to a single private chat.
+    pub fn private_chat(self, rate: Rate) -> Self {
+        RateLimits { private_chat: rate, ..self }
+    }
+
+    /// Limit of requests to a single group, supergroup or channel.
+    pub fn group_chat(self, rate: Rate) -> Self {
+        RateLimits { group_chat: rate, ..self }
+    }
+
+    fn chat(&self, chat: &ChatRef) -> Rate {
+        match *chat {
+            ChatRef::Id(id) if Integer::from(id) > 0 => self.private_chat,
+            _ => self.group_chat,
+        }
+    }
+}
+
+/// Snapshot of the rate limiter queues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimitMetrics {
+    /// Number of requests waiting to be sent.
+    pub queued: usize,
+    /// Number of chats with requests waiting to be sent.
+    pub queued_chats: usize,
+}
+
+/// Source of the current time for the rate limiter.
+pub trait Clock: Debug {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Token bucket implemented as a virtual scheduler: `tat` is the time when
+/// the bucket becomes full again, given all reserved requests are sent.
+#[derive(Debug)]
+struct Bucket {
+    tat: Instant,
+}
+
+impl Bucket {
+    fn new(now: Instant) -> Self {
+        Bucket { tat: now }
+    }
+
+    /// The earliest time a request conforms to the `rate`.
+    fn ready_at(&self, rate: Rate, now: Instant) -> Instant {
+        match self.tat.checked_sub(rate.tolerance()) {
+            Some(ready) => max(ready, now),
+            None => now,
+        }
+    }
+
+    fn take(&mut self, rate: Rate, at: Instant) {
+        self.tat = max(self.tat, at) + rate.interval();
+    }
+}
+
+/// Schedules outgoing requests with token buckets per chat and globally.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limits: RateLimits,
+    clock: Box<Clock>,
+    global: Bucket,
+    chats: HashMap<ChatRef, Bucket>,
+    queued: HashMap<ChatRef, usize>,
+}
+
+impl RateLimiter {
+    pub fn new(limits: RateLimits, clock: Box<Clock>) -> Self {
+        let now = clock.now();
+        RateLimiter {
+            limits: limits,
+            clock: clock,
+            global: Bucket::new(now),
+            chats: HashMap::new(),
+            queued: HashMap::new(),
+        }
+    }
+
+    /// Reserve a slot for a request to `chat`, returns the time
+    /// to wait before the request can be sent.
+    pub fn reserve(&mut self, chat: &ChatRef) -> Duration {
+        let now = self.clock.now();
+        if self.chats.len() > MAX_IDLE_BUCKETS {
+            // Buckets which are full again are indistinguishable from the new ones.
+            self.chats.retain(|_, bucket| bucket.tat > now);
+        }
+
+        let global_rate = self.limits.global;
+        let chat_rate = self.limits.chat(chat);
+        let bucket = self.chats.entry(chat.clone()).or_insert_with(|| Bucket::new(now));
+
+        let ready = max(self.global.ready_at(global_rate, now), bucket.ready_at(chat_rate, now));
+        self.global.take(global_rate, ready);
+        bucket.take(chat_rate, ready);
+
+        *self.queued.entry(chat.clone()).or_insert(0) += 1;
+        ready - now
+    }
+
+    /// Mark a reserved request to `chat` as sent.
+    pub fn release(&mut self, chat: &ChatRef) {
+        let empty = match self.queued.get_mut(chat) {
+            Some(queued) => {
+                *queued -= 1;
+                *queued == 0
+            }
+            None => false,
+        };
+        if empty {
+            self.queued.remove(chat);
+        }
+    }
+
+    pub fn metrics(&self) -> RateLimitMetrics {
+        RateLimitMetrics {
+            queued: self.queued.values().sum(),
+            queued_chats: self.queued.len(),
+        }
+    }
+}
+
+/// Reserved slot of a request, released when dropped.
+pub struct Reservation {
+    limiter: Rc<RefCell<RateLimiter>>,
+    chat: ChatRef,
+    delay: Duration,
+}
+
+impl Reservation {
+    pub fn new(limiter: &Rc<RefCell<RateLimiter>>, chat: ChatRef) -> Self {
+        let delay = limiter.borrow_mut().reserve(&chat);
+        Reservation {
+            limiter: limiter.clone(),
+            chat: chat,
+            delay: delay,
+        }
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.limiter.borrow_mut().release(&self.chat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use telegram_bot_raw::{ToChatRef, UserId, SupergroupId};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct FakeClock {
+        now: Rc<Cell<Instant>>,
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    fn limiter(limits: RateLimits) -> (RateLimiter, Rc<Cell<Instant>>) {
+        let now = Rc::new(Cell::new(Instant::now()));
+        (RateLimiter::new(limits, Box::new(FakeClock { now: now.clone() })), now)
+    }
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn private_chat_limit() {
+        let (mut limiter, now) = limiter(RateLimits::new());
+        let chat = UserId::new(1).to_chat_ref();
+
+        assert_eq!(limiter.reserve(&chat), millis(0));
+        assert_eq!(limiter.reserve(&chat), millis(1000));
+        assert_eq!(limiter.reserve(&chat), millis(2000));
+
+        now.set(now.get() + millis(1500));
+        assert_eq!(limiter.reserve(&chat), millis(1500));
+
+        now.set(now.get() + millis(10000));
+        assert_eq!(limiter.reserve(&chat), millis(0));
+    }
+
+    #[test]
+    fn group_chat_burst() {
+        let (mut limiter, _) = limiter(RateLimits::new().global(Rate::per_second(1000)));
+        let chat = SupergroupId::new(-1).to_chat_ref();
+
+        for _ in 0..20 {
+            assert_eq!(limiter.reserve(&chat), millis(0));
+        }
+        assert_eq!(limiter.reserve(&chat), millis(3000));
+    }
+
+    #[test]
+    fn global_limit() {
+        let (mut limiter, _) = limiter(RateLimits::new().global(Rate::per_second(2)));
+
+        assert_eq!(limiter.reserve(&UserId::new(1).to_chat_ref()), millis(0));
+        assert_eq!(limiter.reserve(&UserId::new(2).to_chat_ref()), millis(0));
+        assert_eq!(limiter.reserve(&UserId::new(3).to_chat_ref()), millis(500));
+        assert_eq!(limiter.reserve(&UserId::new(4).to_chat_ref()), millis(1000));
+    }
+
+    #[test]
+    fn queue_metrics() {
+        let (mut limiter, _) = limiter(RateLimits::new());
+        let first = UserId::new(1).to_chat_ref();
+        let second = UserId::new(2).to_chat_ref();
+
+        limiter.reserve(&first);
+        limiter.reserve(&first);
+        limiter.reserve(&second);
+        assert_eq!(limiter.metrics(), RateLimitMetrics { queued: 3, queued_chats: 2 });
+
+        limiter.release(&first);
+        limiter.release(&second);
+        assert_eq!(limiter.metrics(), RateLimitMetrics { queued: 1, queued_chats: 1 });
+
+        limiter.release(&first);
+        assert_eq!(limiter.metrics(), RateLimitMetrics::default());
+    }
+}
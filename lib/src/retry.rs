@@ -0,0 +1,165 @@
+//! Handling of the Telegram flood control.
+
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use telegram_bot_raw::{self, Integer, RequestUrl, ResponseParameters};
+
+use errors::{Error, ErrorKind};
+
+/// Information about a request rejected by the flood control.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Throttling {
+    /// Url of the rejected request.
+    pub url: RequestUrl,
+    /// Time to wait before the request will be sent again.
+    pub retry_after: Duration,
+    /// Number of the rejected attempt, starting from 1.
+    pub attempt: u32,
+}
+
+/// Policy of re-sending requests rejected by the flood control.
+///
+/// When Telegram responds with `retry_after` parameter, the request is sent again
+/// after the specified number of seconds, until `max_attempts` attempts are made.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    on_throttle: Option<Rc<Fn(&Throttling)>>,
+}
+
+impl RetryPolicy {
+    /// Make at most `max_attempts` attempts to send a request, including the first one.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts,
+            on_throttle: None,
+        }
+    }
+
+    /// Call `hook` every time a request is rejected by the flood control
+    /// and will be sent again.
+    pub fn on_throttle<F>(self, hook: F) -> Self where F: Fn(&Throttling) + 'static {
+        RetryPolicy {
+            max_attempts: self.max_attempts,
+            on_throttle: Some(Rc::new(hook)),
+        }
+    }
+
+    /// Returns the time to wait before the next attempt or `None`
+    /// if the request shouldn't be sent again.
+    pub fn throttle(&self, url: &RequestUrl, error: &Error, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None
+        }
+
+        let throttling = Throttling {
+            url: url.clone(),
+            retry_after: Duration::from_secs(retry_after(error)?.max(0) as u64),
+            attempt: attempt,
+        };
+
+        if let Some(ref hook) = self.on_throttle {
+            hook(&throttling)
+        }
+
+        Some(throttling.retry_after)
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("on_throttle", &self.on_throttle.is_some())
+            .finish()
+    }
+}
+
+fn retry_after(error: &Error) -> Option<Integer> {
+    match *error.kind() {
+        ErrorKind::Raw(telegram_bot_raw::ErrorKind::TelegramError {
+            parameters: Some(ResponseParameters { retry_after: Some(seconds), .. }), ..
+        }) => Some(seconds),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{GetMe, RequestUrl};
+
+    use api::Api;
+    use connector::MockConnector;
+    use errors::{Error, ErrorKind};
+    use super::*;
+
+    fn connector(throttled: usize) -> MockConnector {
+        let mock = MockConnector::new();
+        for _ in 0..throttled {
+            mock.respond_error("getMe", 429, "Too Many Requests: retry after 0", Some(json!({"retry_after": 0})));
+        }
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+        mock
+    }
+
+    #[test]
+    fn retry_after_throttling() {
+        let mut core = Core::new().unwrap();
+        let mock = connector(2);
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        let hook_events = events.clone();
+        let policy = RetryPolicy::new(3).on_throttle(move |throttling| {
+            hook_events.borrow_mut().push(throttling.clone())
+        });
+        let api = Api::configure("token")
+            .connector(mock.boxed())
+            .retry_policy(policy)
+            .build(core.handle()).unwrap();
+
+        let me = core.run(api.send(GetMe)).unwrap();
+        assert_eq!(me.first_name, "Bot");
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(*events.borrow(), vec![
+            Throttling { url: RequestUrl::method("getMe"), retry_after: Duration::from_secs(0), attempt: 1 },
+            Throttling { url: RequestUrl::method("getMe"), retry_after: Duration::from_secs(0), attempt: 2 },
+        ]);
+    }
+
+    #[test]
+    fn retry_max_attempts() {
+        let mut core = Core::new().unwrap();
+        let mock = connector(2);
+
+        let api = Api::configure("token")
+            .connector(mock.boxed())
+            .retry_policy(RetryPolicy::new(2))
+            .build(core.handle()).unwrap();
+
+        match core.run(api.send(GetMe)) {
+            Err(Error(ErrorKind::Raw(_), _)) => (),
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[test]
+    fn no_retry_by_default() {
+        let mut core = Core::new().unwrap();
+        let mock = connector(1);
+
+        let api = Api::configure("token")
+            .connector(mock.boxed())
+            .build(core.handle()).unwrap();
+
+        assert!(core.run(api.send(GetMe)).is_err());
+        assert_eq!(mock.requests().len(), 1);
+    }
+}
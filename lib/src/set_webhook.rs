@@ -0,0 +1,61 @@
+use telegram_bot_raw::{DeleteWebhook, GetWebhookInfo, SetWebhook, WebhookInfo};
+
+use api::Api;
+use future::TelegramFuture;
+
+/// Receive incoming updates via an outgoing webhook to the url,
+/// use `SetWebhook` to upload a certificate or set other options.
+pub trait CanSetWebhook {
+    fn set_webhook<U: Into<String>>(&self, url: U) -> TelegramFuture<()>;
+}
+
+impl CanSetWebhook for Api {
+    fn set_webhook<U: Into<String>>(&self, url: U) -> TelegramFuture<()> {
+        self.send(SetWebhook::new(url.into()))
+    }
+}
+
+/// Remove the webhook to switch back to `getUpdates`.
+pub trait CanDeleteWebhook {
+    fn delete_webhook(&self) -> TelegramFuture<()>;
+}
+
+impl CanDeleteWebhook for Api {
+    fn delete_webhook(&self) -> TelegramFuture<()> {
+        self.send(DeleteWebhook)
+    }
+}
+
+/// Get the current status of the webhook.
+pub trait CanGetWebhookInfo {
+    fn webhook_info(&self) -> TelegramFuture<WebhookInfo>;
+}
+
+impl CanGetWebhookInfo for Api {
+    fn webhook_info(&self) -> TelegramFuture<WebhookInfo> {
+        self.send(GetWebhookInfo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_core::reactor::Core;
+
+    use connector::MockConnector;
+    use super::*;
+
+    #[test]
+    fn set_and_delete_webhook() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("setWebhook", json!(true)).respond("deleteWebhook", json!(true));
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        core.run(api.set_webhook("https://example.com/hook")).unwrap();
+        core.run(api.delete_webhook()).unwrap();
+
+        let request = &mock.requests_to("setWebhook")[0];
+        assert_eq!(request.param("url"), Some(&json!("https://example.com/hook")));
+        assert_eq!(mock.requests_to("deleteWebhook").len(), 1);
+    }
+}
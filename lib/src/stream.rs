@@ -1,19 +1,27 @@
-use std::cmp::max;
-use std::collections::VecDeque;
+use std::cell::RefCell;
+use std::cmp::{max, min};
+use std::collections::{BTreeSet, VecDeque};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::fmt;
+use std::rc::Rc;
 use std::time::Duration;
 
 use futures::{Future, Stream, Poll, Async};
 use futures::future;
+use futures::task::{self, Task};
 use tokio_core::reactor::{Handle, Timeout};
 
-use telegram_bot_raw::{GetUpdates, Update, Integer};
+use telegram_bot_raw::{self, AllowedUpdate, GetUpdates, Update, Integer};
 
 use api::Api;
-use errors::Error;
+use errors::{Error, ErrorKind};
 use future::{TelegramFuture, NewTelegramFuture};
+use offset::OffsetStore;
 
 const TELEGRAM_LONG_POLL_TIMEOUT_SECONDS: u64 = 5;
 const TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS: u64 = 500;
+const TELEGRAM_LONG_POLL_MAX_ERROR_DELAY_SECONDS: u64 = 30;
 
 /// This type represents stream of Telegram API updates and uses
 /// long polling method under the hood.
@@ -25,7 +33,87 @@ pub struct UpdatesStream {
     buffer: VecDeque<Update>,
     current_request: Option<TelegramFuture<Option<Vec<Update>>>>,
     timeout: Duration,
-    error_delay: Duration
+    error_delay: Duration,
+    max_error_delay: Duration,
+    consecutive_errors: u32,
+    allowed_updates: Vec<AllowedUpdate>,
+    limit: Option<Integer>,
+    is_fatal_error: Box<Fn(&Error) -> bool>,
+    drop_pending_updates: bool,
+    finished: bool,
+    offsets: Rc<RefCell<Offsets>>,
+}
+
+/// Offsets of yielded and processed updates, shared with `UpdateAcks`.
+struct Offsets {
+    store: Option<Box<OffsetStore>>,
+    loaded: bool,
+    manual_ack: bool,
+    last_yielded: Integer,
+    confirmed: Integer,
+    pending: BTreeSet<Integer>,
+    task: Option<Task>,
+}
+
+impl Offsets {
+    /// Offset saved in the store, loaded once.
+    fn load(&mut self) -> Result<Option<Integer>, Error> {
+        if self.loaded {
+            return Ok(None)
+        }
+
+        let offset = match self.store {
+            Some(ref store) => store.load()?,
+            None => None,
+        };
+        if let Some(offset) = offset {
+            self.last_yielded = max(self.last_yielded, offset);
+            self.confirmed = max(self.confirmed, offset);
+        }
+        self.loaded = true;
+        Ok(offset)
+    }
+
+    fn yielded(&mut self, update_id: Integer) -> Result<(), Error> {
+        self.last_yielded = max(self.last_yielded, update_id);
+        if self.manual_ack {
+            self.pending.insert(update_id);
+            Ok(())
+        } else {
+            self.confirm(update_id)
+        }
+    }
+
+    fn ack(&mut self, update_id: Integer) -> Result<(), Error> {
+        self.pending.remove(&update_id);
+        let confirmed = match self.pending.iter().next() {
+            Some(pending) => pending - 1,
+            None => self.last_yielded,
+        };
+        if self.pending.is_empty() {
+            if let Some(task) = self.task.take() {
+                task.notify()
+            }
+        }
+        self.confirm(confirmed)
+    }
+
+    /// Confirm updates which are dropped without being yielded.
+    fn skip(&mut self, update_id: Integer) -> Result<(), Error> {
+        self.last_yielded = max(self.last_yielded, update_id);
+        self.confirm(update_id)
+    }
+
+    fn confirm(&mut self, update_id: Integer) -> Result<(), Error> {
+        if update_id <= self.confirmed {
+            return Ok(())
+        }
+        if let Some(ref store) = self.store {
+            store.save(update_id)?;
+        }
+        self.confirmed = update_id;
+        Ok(())
+    }
 }
 
 impl Stream for UpdatesStream {
@@ -34,9 +122,17 @@ impl Stream for UpdatesStream {
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
         if let Some(value) = self.buffer.pop_front() {
+            if let Err(err) = self.offsets.borrow_mut().yielded(value.id) {
+                self.buffer.push_front(value);
+                return Err(err)
+            }
             return Ok(Async::Ready(Some(value)))
         }
 
+        if self.finished {
+            return Ok(Async::Ready(None))
+        }
+
         let result = match self.current_request {
             None => Ok(false),
             Some(ref mut current_request) => {
@@ -45,9 +141,21 @@ impl Stream for UpdatesStream {
                     Ok(Async::NotReady) => return Ok(Async::NotReady),
                     Ok(Async::Ready(None)) => Ok(false),
                     Ok(Async::Ready(Some(updates))) => {
-                        for update in updates {
-                            self.last_update = max(update.id, self.last_update);
-                            self.buffer.push_back(update)
+                        self.consecutive_errors = 0;
+                        if self.drop_pending_updates {
+                            if let Some(last_update) = updates.iter().map(|update| update.id).max() {
+                                self.last_update = max(last_update, self.last_update);
+                                let skipped = self.offsets.borrow_mut().skip(last_update);
+                                if let Err(err) = skipped {
+                                    return self.retry_after_error(err)
+                                }
+                            }
+                            self.drop_pending_updates = false;
+                        } else {
+                            for update in updates {
+                                self.last_update = max(update.id, self.last_update);
+                                self.buffer.push_back(update)
+                            }
                         }
                         Ok(true)
                     },
@@ -57,23 +165,37 @@ impl Stream for UpdatesStream {
         };
 
         match result {
-            Err(err) => {
-                let timeout_future = future::result(Timeout::new(self.error_delay, &self.handle));
-
-                let timeout_future = timeout_future.map_err(From::from).and_then(|timeout| {
-                    timeout.map_err(From::from).map(|()| None)
-                });
-
-                self.current_request = Some(TelegramFuture::new(Box::new(timeout_future)));
-                return Err(err)
-            }
+            Err(err) => self.retry_after_error(err),
             Ok(false) => {
+                let loaded = self.offsets.borrow_mut().load();
+                match loaded {
+                    Ok(Some(offset)) => self.last_update = max(self.last_update, offset),
+                    Ok(None) => (),
+                    Err(err) => return self.retry_after_error(err),
+                }
+                {
+                    let mut offsets = self.offsets.borrow_mut();
+                    if !offsets.pending.is_empty() {
+                        offsets.task = Some(task::current());
+                        return Ok(Async::NotReady)
+                    }
+                }
+
                 let timeout = self.timeout + Duration::from_secs(1);
 
-                let request = self.api.send_timeout(GetUpdates::new()
-                    .offset(self.last_update + 1)
-                    .timeout(self.timeout.as_secs() as Integer)
-                , timeout);
+                let mut request = GetUpdates::new();
+                request.allowed_updates(&self.allowed_updates);
+                if self.drop_pending_updates {
+                    // Only the last pending update is returned for the negative offset.
+                    request.offset(-1).timeout(0);
+                } else {
+                    request.offset(self.last_update + 1).timeout(self.timeout.as_secs() as Integer);
+                    if let Some(limit) = self.limit {
+                        request.limit(limit);
+                    }
+                }
+
+                let request = self.api.send_timeout(request, timeout);
 
                 self.current_request = Some(request);
                 self.poll()
@@ -99,7 +221,23 @@ impl NewUpdatesStream for UpdatesStream{
             buffer: VecDeque::new(),
             current_request: None,
             timeout: Duration::from_secs(TELEGRAM_LONG_POLL_TIMEOUT_SECONDS),
-            error_delay: Duration::from_millis(TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS)
+            error_delay: Duration::from_millis(TELEGRAM_LONG_POLL_ERROR_DELAY_MILLISECONDS),
+            max_error_delay: Duration::from_secs(TELEGRAM_LONG_POLL_MAX_ERROR_DELAY_SECONDS),
+            consecutive_errors: 0,
+            allowed_updates: Vec::new(),
+            limit: None,
+            is_fatal_error: Box::new(UpdatesStream::is_fatal_error),
+            drop_pending_updates: false,
+            finished: false,
+            offsets: Rc::new(RefCell::new(Offsets {
+                store: None,
+                loaded: false,
+                manual_ack: false,
+                last_yielded: 0,
+                confirmed: 0,
+                pending: BTreeSet::new(),
+                task: None,
+            })),
         }
     }
 }
@@ -119,9 +257,362 @@ impl UpdatesStream {
     /// Set a delay between erroneous request and next request.
     /// This delay prevents busy looping in some cases.
     ///
+    /// The delay is doubled after every consecutive error up to `max_error_delay`,
+    /// a random jitter of up to a half of the delay is subtracted from it.
+    ///
     /// Default delay is 500 ms.
     pub fn error_delay(&mut self, delay: Duration) -> &mut Self {
         self.error_delay = delay;
         self
     }
+
+    /// Set the maximal delay between erroneous requests, see `error_delay`.
+    ///
+    /// Default maximal delay is 30 seconds.
+    pub fn max_error_delay(&mut self, delay: Duration) -> &mut Self {
+        self.max_error_delay = delay;
+        self
+    }
+
+    /// Receive only updates of the listed kinds, this corresponds with `allowed_updates` field
+    /// in [getUpdates](https://core.telegram.org/bots/api#getupdates) method.
+    ///
+    /// By default the previous setting of the bot is used.
+    pub fn allowed_updates(&mut self, updates: &[AllowedUpdate]) -> &mut Self {
+        self.allowed_updates = updates.to_vec();
+        self
+    }
+
+    /// Limit the number of updates received with a request, values between 1 and 100 are accepted.
+    ///
+    /// Default limit is 100.
+    pub fn limit(&mut self, limit: Integer) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set a predicate for errors after which the stream ends instead of making the next request.
+    ///
+    /// By default the stream ends after the errors of `UpdatesStream::is_fatal_error`.
+    pub fn fatal_error<F>(&mut self, is_fatal_error: F) -> &mut Self where F: Fn(&Error) -> bool + 'static {
+        self.is_fatal_error = Box::new(is_fatal_error);
+        self
+    }
+
+    /// Skip updates which are pending when the stream starts.
+    pub fn drop_pending_updates(&mut self) -> &mut Self {
+        self.drop_pending_updates = true;
+        self
+    }
+
+    /// Returns `true` for errors which are not resolved by repeating the request: an invalid
+    /// bot token or a conflict with a webhook or another `getUpdates` request.
+    pub fn is_fatal_error(error: &Error) -> bool {
+        match *error.kind() {
+            ErrorKind::Raw(telegram_bot_raw::ErrorKind::TelegramError { error_code: Some(code), .. }) => {
+                code == 401 || code == 404 || code == 409
+            }
+            _ => false,
+        }
+    }
+
+    /// End the stream after a fatal error, otherwise send the next request after the error delay.
+    fn retry_after_error(&mut self, err: Error) -> Poll<Option<Update>, Error> {
+        if (self.is_fatal_error)(&err) {
+            self.finished = true;
+            self.current_request = None;
+            return Err(err)
+        }
+
+        let timeout_future = future::result(Timeout::new(self.next_error_delay(), &self.handle));
+
+        let timeout_future = timeout_future.map_err(From::from).and_then(|timeout| {
+            timeout.map_err(From::from).map(|()| None)
+        });
+
+        self.current_request = Some(TelegramFuture::new(Box::new(timeout_future)));
+        Err(err)
+    }
+
+    fn next_error_delay(&mut self) -> Duration {
+        let factor = 1u32 << min(self.consecutive_errors, 16);
+        self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+
+        let delay = self.error_delay.checked_mul(factor).map_or(self.max_error_delay, |delay| {
+            min(delay, self.max_error_delay)
+        });
+        let nanos = delay.as_secs() * 1_000_000_000 + u64::from(delay.subsec_nanos());
+
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(self.consecutive_errors);
+        let jitter = hasher.finish() % (nanos / 2 + 1);
+        Duration::from_nanos(nanos - jitter)
+    }
+
+    /// Continue from the offset saved in the `store` and save the ids of yielded updates to it,
+    /// so updates are neither replayed nor lost after a restart.
+    ///
+    /// By default an update is saved as processed when it is yielded, use `manual_ack`
+    /// to save it after it is handled.
+    pub fn with_offset_store<S: OffsetStore + 'static>(&mut self, store: S) -> &mut Self {
+        {
+            let mut offsets = self.offsets.borrow_mut();
+            offsets.store = Some(Box::new(store));
+            offsets.loaded = false;
+        }
+        self
+    }
+
+    /// Advance the offset only for updates acknowledged with the returned `UpdateAcks`,
+    /// updates yielded but not acknowledged are received again after a restart.
+    ///
+    /// The stream waits for all yielded updates to be acknowledged before requesting new ones,
+    /// so updates whose handling failed have to be given up with `UpdateAcks::skip`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # extern crate futures;
+    /// # extern crate telegram_bot;
+    /// # extern crate tokio_core;
+    /// use futures::{Future, Stream};
+    /// use telegram_bot::{Api, FileOffsetStore};
+    /// use tokio_core::reactor::Core;
+    ///
+    /// # fn handle(_: telegram_bot::Update) -> Result<(), ()> { Ok(()) }
+    /// # fn main() {
+    /// let mut core = Core::new().unwrap();
+    /// # let telegram_token = "token";
+    /// let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+    ///
+    /// let mut stream = api.stream();
+    /// let acks = stream.with_offset_store(FileOffsetStore::new("offset")).manual_ack();
+    ///
+    /// let future = stream.for_each(|update| {
+    ///     let update_id = update.id;
+    ///     match handle(update) {
+    ///         Ok(()) => acks.ack(update_id),
+    ///         Err(()) => acks.skip(update_id),
+    ///     }
+    /// });
+    /// core.run(future).unwrap();
+    /// # }
+    /// ```
+    pub fn manual_ack(&mut self) -> UpdateAcks {
+        self.offsets.borrow_mut().manual_ack = true;
+        UpdateAcks {
+            offsets: self.offsets.clone(),
+        }
+    }
+}
+
+/// Handle to acknowledge updates processed in the manual ack mode of `UpdatesStream`.
+#[derive(Clone)]
+pub struct UpdateAcks {
+    offsets: Rc<RefCell<Offsets>>,
+}
+
+impl UpdateAcks {
+    /// Mark the update with `update_id` as processed, the saved offset advances once
+    /// all updates yielded before it are acknowledged.
+    pub fn ack(&self, update_id: Integer) -> Result<(), Error> {
+        self.offsets.borrow_mut().ack(update_id)
+    }
+
+    /// Give up on the update with `update_id`, e.g. after its handler failed,
+    /// so that the stream stops waiting for it. The update is not received again.
+    pub fn skip(&self, update_id: Integer) -> Result<(), Error> {
+        self.offsets.borrow_mut().ack(update_id)
+    }
+
+    /// Id of the last update which is processed together with all updates before it.
+    pub fn confirmed(&self) -> Integer {
+        self.offsets.borrow().confirmed
+    }
+}
+
+impl fmt::Debug for UpdateAcks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UpdateAcks")
+            .field("confirmed", &self.offsets.borrow().confirmed)
+            .field("pending", &self.offsets.borrow().pending)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use futures::Stream;
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::UserId;
+    use serde_json::Value;
+
+    use connector::MockConnector;
+    use offset::MemoryOffsetStore;
+    use super::*;
+
+    fn api(core: &Core, mock: &MockConnector) -> Api {
+        for text in &["first", "second", "third"] {
+            mock.push_text_message(UserId::new(1), text);
+        }
+        Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap()
+    }
+
+    #[test]
+    fn continue_from_saved_offset() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+        let store = MemoryOffsetStore::new();
+        store.save(1).unwrap();
+
+        let mut stream = api.stream();
+        stream.with_offset_store(store.clone());
+        let ids: Vec<_> = core.run(stream.take(2).map(|update| update.id).collect()).unwrap();
+
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(store.offset(), Some(3));
+        assert_eq!(mock.requests_to("getUpdates")[0].param("offset"), Some(&json!(2)));
+    }
+
+    #[test]
+    fn advance_offset_on_ack() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+        let store = MemoryOffsetStore::new();
+
+        let mut stream = api.stream();
+        let acks = stream.with_offset_store(store.clone()).manual_ack();
+        core.run(stream.by_ref().take(2).collect()).unwrap();
+        assert_eq!(store.offset(), None);
+
+        acks.ack(2).unwrap();
+        assert_eq!(store.offset(), None);
+        acks.ack(1).unwrap();
+        assert_eq!(store.offset(), Some(2));
+
+        let (update, _) = core.run(stream.into_future()).map_err(|(err, _)| err).unwrap();
+        acks.ack(update.unwrap().id).unwrap();
+        assert_eq!((acks.confirmed(), store.offset()), (3, Some(3)));
+    }
+
+    #[test]
+    fn request_options() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+
+        let mut stream = api.stream();
+        stream.allowed_updates(&[AllowedUpdate::Message]).limit(10);
+        core.run(stream.take(1).collect()).unwrap();
+
+        let request = &mock.requests_to("getUpdates")[0];
+        assert_eq!(request.param("allowed_updates"), Some(&json!(["message"])));
+        assert_eq!(request.param("limit"), Some(&json!(10)));
+    }
+
+    #[test]
+    fn end_after_fatal_error() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond_error("getUpdates", 500, "Internal Server Error", None);
+        mock.respond_error("getUpdates", 401, "Unauthorized", None);
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let mut stream = api.stream();
+        stream.error_delay(Duration::from_millis(0));
+        let results = core.run(stream.then(|result| Ok::<_, ()>(result.is_ok())).collect()).unwrap();
+
+        assert_eq!(results, vec![false, false]);
+        assert_eq!(mock.requests_to("getUpdates").len(), 2);
+    }
+
+    #[test]
+    fn backoff_consecutive_errors() {
+        let core = Core::new().unwrap();
+        let api = Api::configure("token").connector(MockConnector::new().boxed()).build(core.handle()).unwrap();
+
+        let mut stream = api.stream();
+        stream.error_delay(Duration::from_millis(100)).max_error_delay(Duration::from_millis(300));
+        for &(low, high) in &[(50, 100), (100, 200), (150, 300), (150, 300)] {
+            let delay = stream.next_error_delay();
+            assert!(delay >= Duration::from_millis(low) && delay <= Duration::from_millis(high), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn skip_failed_update() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+        let store = MemoryOffsetStore::new();
+
+        let mut stream = api.stream();
+        let acks = stream.with_offset_store(store.clone()).manual_ack();
+        let handled = stream.by_ref().take(3).for_each(|update| {
+            let handled: Result<(), ()> = if update.id == 2 { Err(()) } else { Ok(()) };
+            match handled {
+                Ok(()) => acks.ack(update.id),
+                Err(()) => acks.skip(update.id),
+            }
+        });
+        core.run(handled).unwrap();
+        assert_eq!(store.offset(), Some(3));
+
+        mock.push_text_message(UserId::new(1), "fourth");
+        let (update, _) = core.run(stream.into_future()).map_err(|(err, _)| err).unwrap();
+        assert_eq!(update.map(|update| update.id), Some(4));
+    }
+
+    #[test]
+    fn delay_after_offset_load_error() {
+        struct BrokenStore;
+
+        impl OffsetStore for BrokenStore {
+            fn load(&self) -> Result<Option<Integer>, Error> {
+                Err("corrupt offset".into())
+            }
+
+            fn save(&self, _: Integer) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+
+        let mut stream = api.stream();
+        stream.with_offset_store(BrokenStore).error_delay(Duration::from_millis(100));
+        let started = Instant::now();
+        let results = core.run(stream.then(|result| Ok::<_, ()>(result.is_ok())).take(2).collect()).unwrap();
+
+        assert_eq!(results, vec![false, false]);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert!(mock.requests_to("getUpdates").is_empty());
+    }
+
+    #[test]
+    fn drop_pending_updates() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        let api = api(&core, &mock);
+        let store = MemoryOffsetStore::new();
+
+        let mut stream = api.stream();
+        stream.with_offset_store(store.clone()).drop_pending_updates();
+        let next = stream.into_future().map(|(update, _)| update).map_err(|(err, _)| err);
+        let timeout = Timeout::new(Duration::from_millis(50), &core.handle()).unwrap().map(|()| None).map_err(Error::from);
+        let update = core.run(next.select(timeout).map(|(update, _)| update).map_err(|(err, _)| err)).unwrap();
+
+        assert!(update.is_none());
+        assert_eq!(store.offset(), Some(3));
+        let offsets: Vec<_> = mock.requests_to("getUpdates").iter()
+            .map(|request| request.param("offset").and_then(Value::as_i64)).collect();
+        assert_eq!(offsets, vec![Some(-1), Some(4)]);
+    }
 }
@@ -0,0 +1,235 @@
+//! Thread-safe handle to an `Api`.
+
+use std::fmt;
+
+use futures::{Future, Poll, Stream};
+use futures::future::result;
+use futures::sync::{mpsc, oneshot};
+use tokio_core::reactor::Handle;
+
+use telegram_bot_raw::{self, DetachedRequestType, HttpRequest, HttpResponse, JsonIdResponse};
+use telegram_bot_raw::{Request, ResponseType};
+use serde_json::Value;
+
+use api::Api;
+use errors::{Error, ErrorKind};
+
+/// Represent a future that resolves into Telegram API response and can be sent
+/// between threads.
+#[must_use = "futures do nothing unless polled"]
+pub struct SyncFuture<T> {
+    inner: Box<Future<Item=T, Error=Error> + Send>,
+}
+
+impl<T> Future for SyncFuture<T> {
+    type Item = T;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/// Handle to an `Api` which is `Send` and `Sync` and can be cloned to other threads,
+/// created with `Api::sync_api`.
+///
+/// Connectors and the reactor of an `Api` are bound to the thread running its `Core`,
+/// so requests are serialized on the calling thread and sent by the `Api` on the
+/// `Core` thread, with its rate limits, retry policy and migrations. Requests fail
+/// with `ErrorKind::ApiShutdown` once the `Core` is dropped.
+///
+/// All IO still happens on the `Core` thread, so a `SyncFuture` completes only while
+/// the `Core` is running. Blocking on it with `wait` on the thread which runs the `Core`,
+/// e.g. inside a handler of an update, deadlocks. Use the `SyncApi` only from other
+/// threads and the `Api` itself on the `Core` thread.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate telegram_bot;
+/// # extern crate tokio_core;
+/// use std::thread;
+///
+/// use futures::Future;
+/// use telegram_bot::{Api, GetMe};
+/// use tokio_core::reactor::Core;
+///
+/// # fn main() {
+/// let mut core = Core::new().unwrap();
+/// # let telegram_token = "token";
+/// let api = Api::configure(telegram_token).build(core.handle()).unwrap();
+///
+/// let sync_api = api.sync_api();
+/// let worker = thread::spawn(move || {
+///     sync_api.send(GetMe).wait()
+/// });
+/// # if false {
+/// // Drive the requests of the worker.
+/// core.run(futures::future::empty::<(), ()>()).unwrap();
+/// # }
+/// # drop(worker);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SyncApi {
+    sender: mpsc::UnboundedSender<Job>,
+}
+
+impl fmt::Debug for SyncApi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SyncApi")
+    }
+}
+
+struct Job {
+    request: HttpRequest,
+    reply: Option<oneshot::Sender<Result<HttpResponse, Error>>>,
+}
+
+/// Request sent on behalf of a `SyncApi`, the response is deserialized by the caller.
+struct ForwardedRequest {
+    request: Result<HttpRequest, telegram_bot_raw::Error>,
+}
+
+impl Request for ForwardedRequest {
+    type Type = DetachedRequestType;
+    type Response = ForwardedResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, telegram_bot_raw::Error> {
+        <Self::Type as telegram_bot_raw::RequestType>::serialize((), &self.request)
+    }
+}
+
+/// Checks the response for Telegram errors, so they are handled by the `Api`.
+struct ForwardedResponse;
+
+impl ResponseType for ForwardedResponse {
+    type Type = HttpResponse;
+
+    fn deserialize(resp: HttpResponse) -> Result<Self::Type, telegram_bot_raw::Error> {
+        <JsonIdResponse<Value> as ResponseType>::deserialize(resp.clone())?;
+        Ok(resp)
+    }
+}
+
+pub trait NewSyncApi {
+    fn new(api: &Api, handle: &Handle) -> Self;
+}
+
+impl NewSyncApi for SyncApi {
+    fn new(api: &Api, handle: &Handle) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let api = api.clone();
+        let spawn_handle = handle.clone();
+        handle.spawn(receiver.for_each(move |Job { request, reply }| {
+            let response = api.send(ForwardedRequest { request: Ok(request) });
+            spawn_handle.spawn(response.then(move |response| {
+                if let Some(reply) = reply {
+                    let _ = reply.send(response);
+                }
+                Ok(())
+            }));
+            Ok(())
+        }));
+
+        SyncApi {
+            sender: sender,
+        }
+    }
+}
+
+impl SyncApi {
+    /// Send a request to the Telegram server and wait for a response.
+    pub fn send<Req: Request>(&self, request: Req) -> SyncFuture<<Req::Response as ResponseType>::Type>
+        where <Req::Response as ResponseType>::Type: Send
+    {
+        let (reply, response) = oneshot::channel();
+        let sent = request.serialize().map_err(Error::from).and_then(|request| {
+            self.enqueue(Job { request: request, reply: Some(reply) })
+        });
+
+        let future = result(sent).and_then(|()| {
+            response.map_err(|_| Error::from(ErrorKind::ApiShutdown)).and_then(|response| response)
+        }).and_then(|response| {
+            <Req::Response as ResponseType>::deserialize(response).map_err(From::from)
+        });
+
+        SyncFuture {
+            inner: Box::new(future),
+        }
+    }
+
+    /// Send a request to the Telegram server and do not wait for a response.
+    pub fn spawn<Req: Request>(&self, request: Req) -> Result<(), Error> {
+        let request = request.serialize()?;
+        self.enqueue(Job { request: request, reply: None })
+    }
+
+    fn enqueue(&self, job: Job) -> Result<(), Error> {
+        self.sender.unbounded_send(job).map_err(|_| ErrorKind::ApiShutdown.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use futures::Future;
+    use futures::sync::oneshot;
+    use tokio_core::reactor::Core;
+
+    use telegram_bot_raw::{ChatId, GetMe, Update, User, CanSendMessage};
+
+    use api::Api;
+    use connector::MockConnector;
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn thread_safe() {
+        assert_send_sync::<SyncApi>();
+        assert_send_sync::<Update>();
+        assert_send::<SyncFuture<User>>();
+    }
+
+    #[test]
+    fn send_from_thread() {
+        let mut core = Core::new().unwrap();
+        let mock = MockConnector::new();
+        mock.respond("getMe", json!({"id": 1, "first_name": "Bot", "username": "bot"}));
+        mock.respond_error("sendMessage", 403, "Forbidden: bot was blocked by the user", None);
+        let api = Api::configure("token").connector(mock.boxed()).build(core.handle()).unwrap();
+
+        let sync_api = api.sync_api();
+        let (sender, done) = oneshot::channel();
+        thread::spawn(move || {
+            let me: Result<User, Error> = sync_api.send(GetMe).wait();
+            let sent = sync_api.send(ChatId::new(1).text("text")).wait();
+            let _ = sender.send((me.map(|me| me.first_name), sent.is_err()));
+        });
+
+        let (me, failed) = core.run(done).unwrap();
+        assert_eq!(me.unwrap(), "Bot");
+        assert!(failed);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[test]
+    fn fail_after_shutdown() {
+        let sync_api = {
+            let core = Core::new().unwrap();
+            let api = Api::configure("token").connector(MockConnector::new().boxed()).build(core.handle()).unwrap();
+            api.sync_api()
+        };
+
+        match sync_api.send(GetMe).wait() {
+            Err(Error(ErrorKind::ApiShutdown, _)) => (),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
@@ -4,20 +4,40 @@ pub mod requests;
 pub use requests::*;
 
 pub use telegram_bot_raw::{Integer, Float, Request, DetachedRequest};
+pub use telegram_bot_raw::BaseUrl;
+pub use telegram_bot_raw::{HttpRequest, HttpResponse, RequestUrl, Method, Body, Multipart, MultipartValue};
 pub use telegram_bot_raw::{ResponseType, JsonResponse, JsonIdResponse, JsonTrueToUnitResponse};
 pub use telegram_bot_raw::{Update, UpdateKind};
-pub use telegram_bot_raw::{User, Group, Supergroup, Channel, Chat, MessageChat};
+pub use telegram_bot_raw::{User, Group, Supergroup, Channel, Chat, MessageChat, ChatFullInfo, ChatPhoto};
 pub use telegram_bot_raw::{UserId, GroupId, SupergroupId, ChannelId, ChatId, ChatRef};
+pub use telegram_bot_raw::BotCommand;
 pub use telegram_bot_raw::{CallbackQuery, CallbackQueryId};
+pub use telegram_bot_raw::{InlineQuery, InlineQueryId, ChosenInlineResult};
+pub use telegram_bot_raw::{InlineQueryResult, InlineQueryResultArticle, InlineQueryResultPhoto};
+pub use telegram_bot_raw::{InlineQueryResultGif, InlineQueryResultMpeg4Gif, InlineQueryResultVideo};
+pub use telegram_bot_raw::{InlineQueryResultAudio, InlineQueryResultVoice, InlineQueryResultDocument};
+pub use telegram_bot_raw::{InlineQueryResultLocation, InlineQueryResultVenue, InlineQueryResultContact};
+pub use telegram_bot_raw::{InlineQueryResultCachedPhoto, InlineQueryResultCachedGif};
+pub use telegram_bot_raw::{InlineQueryResultCachedMpeg4Gif, InlineQueryResultCachedSticker};
+pub use telegram_bot_raw::{InlineQueryResultCachedDocument, InlineQueryResultCachedVideo};
+pub use telegram_bot_raw::{InlineQueryResultCachedVoice, InlineQueryResultCachedAudio};
+pub use telegram_bot_raw::{InputMessageContent, InputTextMessageContent, InputLocationMessageContent};
+pub use telegram_bot_raw::{InputVenueMessageContent, InputContactMessageContent};
 pub use telegram_bot_raw::{MessageId, MessageKind, Forward, ForwardFrom};
 pub use telegram_bot_raw::{MessageOrChannelPost, Message, ChannelPost};
 pub use telegram_bot_raw::{MessageEntity, MessageEntityKind};
 pub use telegram_bot_raw::{Audio, Document, PhotoSize, Sticker, Video, Voice};
 pub use telegram_bot_raw::{Contact, Location, Venue, File, FileRef};
+pub use telegram_bot_raw::{InputFile, InputFileUpload};
+pub use telegram_bot_raw::{InputMedia, InputMediaPhoto, InputMediaVideo};
 pub use telegram_bot_raw::{ToChatRef, ToUserId, ToMessageId, ToSourceChat, ToFileRef, ToCallbackQueryId};
+pub use telegram_bot_raw::ToInlineQueryId;
 
 pub use telegram_bot_raw::{ParseMode};
 pub use telegram_bot_raw::{ReplyMarkup, ReplyKeyboardMarkup, KeyboardButton};
-pub use telegram_bot_raw::{InlineKeyboardMarkup, InlineKeyboardButton};
+pub use telegram_bot_raw::{InlineKeyboardMarkup, InlineKeyboardButton, InlineKeyboardButtonKind};
+pub use telegram_bot_raw::{CallbackGame, LoginUrl};
 pub use telegram_bot_raw::{ReplyKeyboardRemove, ForceReply};
 pub use telegram_bot_raw::{ChatAction};
+pub use telegram_bot_raw::ChatPermissions;
+pub use telegram_bot_raw::{WebhookInfo};
@@ -1,13 +1,20 @@
 //! Telegram Bot API methods.
 
-pub use telegram_bot_raw::AnswerCallbackQuery;
+pub use telegram_bot_raw::{AnswerCallbackQuery, AnswerInlineQuery};
 pub use telegram_bot_raw::{DeleteMessage, ForwardMessage};
 pub use telegram_bot_raw::{GetFile, GetMe, GetUpdates, GetUserProfilePhotos};
 pub use telegram_bot_raw::{SendAudio, SendContact, SendLocation, SendMessage, SendVenue};
+pub use telegram_bot_raw::{SendAnimation, SendDocument, SendPhoto, SendSticker};
+pub use telegram_bot_raw::{SendVideo, SendVideoNote, SendVoice};
+pub use telegram_bot_raw::SendMediaGroup;
 pub use telegram_bot_raw::{GetChat, LeaveChat};
+pub use telegram_bot_raw::{SetChatTitle, SetChatDescription, SetChatPhoto, DeleteChatPhoto};
+pub use telegram_bot_raw::{ExportChatInviteLink, SetChatStickerSet, DeleteChatStickerSet};
 pub use telegram_bot_raw::SendChatAction;
 pub use telegram_bot_raw::{GetChatAdministrators, GetChatMember, GetChatMembersCount};
 pub use telegram_bot_raw::{KickChatMember, UnbanChatMember};
+pub use telegram_bot_raw::{RestrictChatMember, PromoteChatMember};
 pub use telegram_bot_raw::{EditMessageCaption, EditMessageReplyMarkup, EditMessageText};
 pub use telegram_bot_raw::{PinChatMessage, UnpinChatMessage};
 pub use telegram_bot_raw::{EditMessageLiveLocation, StopMessageLiveLocation};
+pub use telegram_bot_raw::{SetWebhook, DeleteWebhook, GetWebhookInfo, AllowedUpdate};
@@ -0,0 +1,144 @@
+//! Receiving updates via webhook.
+
+use std::net::SocketAddr;
+use std::rc::Rc;
+
+use futures::{Future, Stream, Poll, Async};
+use futures::future;
+use futures::sync::{mpsc, oneshot};
+use hyper;
+use hyper::{Method, StatusCode};
+use hyper::server::{Http, Request, Response, Service};
+use serde_json;
+use tokio_core::reactor::Handle;
+
+use telegram_bot_raw::Update;
+
+use errors::Error;
+
+/// Maximal size of an update body accepted by `WebhookServer`.
+const MAX_UPDATE_SIZE: usize = 1024 * 1024;
+
+/// This type represents stream of Telegram API updates delivered to
+/// a local HTTP server by the [webhook](https://core.telegram.org/bots/api#setwebhook)
+/// mechanism. It produces the same items as `UpdatesStream`.
+#[must_use = "streams do nothing unless polled"]
+pub struct WebhookServer {
+    local_addr: SocketAddr,
+    receiver: mpsc::UnboundedReceiver<Result<Update, Error>>,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl WebhookServer {
+    /// Start an HTTP server on `addr` which accepts updates posted to `path`.
+    ///
+    /// Requests to any other path are rejected, so the path can be used as a secret
+    /// which is known only to Telegram, e.g. `/<token>`.
+    ///
+    /// Updates which can not be parsed are answered with `200 OK`, so that Telegram
+    /// does not deliver them again, and are returned as errors from the stream.
+    /// Bodies larger than 1 MB are answered with `413 Payload Too Large` without being buffered.
+    ///
+    /// The server is stopped when the stream is dropped.
+    pub fn bind<P: AsRef<str>>(addr: &SocketAddr, path: P, handle: &Handle)
+        -> Result<WebhookServer, Error> {
+
+        let (sender, receiver) = mpsc::unbounded();
+        let (shutdown, shutdown_signal) = oneshot::channel();
+
+        let path = Rc::new(path.as_ref().to_string());
+        let serve = Http::new().serve_addr_handle(addr, handle, move || {
+            Ok(WebhookService {
+                path: path.clone(),
+                sender: sender.clone(),
+            })
+        })?;
+        let local_addr = serve.incoming_ref().local_addr();
+
+        let connection_handle = handle.clone();
+        let server = serve.for_each(move |connection| {
+            connection_handle.spawn(connection.map(|_| ()).map_err(|_| ()));
+            Ok(())
+        });
+
+        handle.spawn(server.select2(shutdown_signal).then(|_| Ok(())));
+
+        Ok(WebhookServer {
+            local_addr: local_addr,
+            receiver: receiver,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// Returns the local address that this server is bound to.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Stream for WebhookServer {
+    type Item = Update;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.receiver.poll() {
+            Ok(Async::Ready(Some(Ok(update)))) => Ok(Async::Ready(Some(update))),
+            Ok(Async::Ready(Some(Err(error)))) => Err(error),
+            Ok(Async::Ready(None)) | Err(()) => Ok(Async::Ready(None)),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+        }
+    }
+}
+
+struct WebhookService {
+    path: Rc<String>,
+    sender: mpsc::UnboundedSender<Result<Update, Error>>,
+}
+
+impl Service for WebhookService {
+    type Request = Request;
+    type Response = Response;
+    type Error = hyper::Error;
+    type Future = Box<Future<Item=Response, Error=hyper::Error>>;
+
+    fn call(&self, request: Request) -> Self::Future {
+        if request.path() != self.path.as_str() {
+            return Box::new(future::ok(Response::new().with_status(StatusCode::NotFound)))
+        }
+
+        if request.method() != &Method::Post {
+            return Box::new(future::ok(Response::new().with_status(StatusCode::MethodNotAllowed)))
+        }
+
+        let sender = self.sender.clone();
+        let body = request.body().map_err(BodyError::Hyper).fold(Vec::new(), |mut body, chunk| {
+            if body.len() + chunk.len() > MAX_UPDATE_SIZE {
+                return Err(BodyError::TooLarge)
+            }
+            body.extend_from_slice(&chunk);
+            Ok(body)
+        });
+
+        let future = body.then(move |body| {
+            let body = match body {
+                Ok(body) => body,
+                Err(BodyError::TooLarge) => return Ok(Response::new().with_status(StatusCode::PayloadTooLarge)),
+                Err(BodyError::Hyper(error)) => return Err(error),
+            };
+
+            let update = serde_json::from_slice::<Update>(&body).map_err(Error::from);
+            let status = match sender.unbounded_send(update) {
+                Ok(()) => StatusCode::Ok,
+                Err(_) => StatusCode::ServiceUnavailable,
+            };
+            Ok(Response::new().with_status(status))
+        });
+
+        Box::new(future)
+    }
+}
+
+enum BodyError {
+    Hyper(hyper::Error),
+    TooLarge,
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "derive")]
+
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot;
+
+use telegram_bot::{BotCommand, BotCommands, CommandError, Message, User};
+
+#[derive(Debug, PartialEq, BotCommands)]
+enum Command {
+    /// Start the bot
+    Start,
+    #[command(description = "Ban a user for some minutes")]
+    Ban { user: String, minutes: u32 },
+    #[command(rename = "help", description = "Show this text")]
+    Usage,
+    SetTitle(String),
+}
+
+fn me() -> User {
+    serde_json::from_value(json!({"id": 1, "first_name": "Bot", "username": "our_bot"})).unwrap()
+}
+
+fn message(text: &str) -> Message {
+    let length = text.split_whitespace().next().unwrap_or("").encode_utf16().count();
+    let entities = if text.starts_with('/') {
+        json!([{"type": "bot_command", "offset": 0, "length": length}])
+    } else {
+        json!([])
+    };
+
+    serde_json::from_value(json!({
+        "message_id": 1,
+        "date": 0,
+        "from": {"id": 2, "first_name": "User"},
+        "chat": {"id": 2, "type": "private", "first_name": "User"},
+        "text": text,
+        "entities": entities,
+    })).unwrap()
+}
+
+fn parse(text: &str) -> Result<Command, CommandError> {
+    Command::parse(&message(text), &me())
+}
+
+#[test]
+fn parse_commands() {
+    assert_eq!(parse("/start"), Ok(Command::Start));
+    assert_eq!(parse("/ban@our_bot alice 15"), Ok(Command::Ban { user: "alice".to_string(), minutes: 15 }));
+    assert_eq!(parse("/help"), Ok(Command::Usage));
+    assert_eq!(parse("/set_title title"), Ok(Command::SetTitle("title".to_string())));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("start"), Err(CommandError::NotCommand));
+    assert_eq!(parse("/start@other_bot"), Err(CommandError::OtherBot("other_bot".to_string())));
+    assert_eq!(parse("/usage"), Err(CommandError::UnknownCommand("usage".to_string())));
+    assert_eq!(parse("/ban alice"), Err(CommandError::MissingArgument {
+        command: "ban".to_string(),
+        argument: "minutes".to_string(),
+    }));
+    assert_eq!(parse("/start now"), Err(CommandError::TooManyArguments {
+        command: "start".to_string(),
+        expected: 0,
+    }));
+    assert_eq!(parse("/ban alice forever").unwrap_err().to_string(),
+               "Invalid argument <minutes> of /ban: \"forever\", invalid digit found in string");
+}
+
+#[test]
+fn describe_commands() {
+    assert_eq!(Command::bot_commands(), vec![
+        BotCommand::new("start", "Start the bot"),
+        BotCommand::new("ban", "Ban a user for some minutes"),
+        BotCommand::new("help", "Show this text"),
+        BotCommand::new("set_title", ""),
+    ]);
+    assert_eq!(Command::help(), "/start - Start the bot\n\
+                                 /ban <user> <minutes> - Ban a user for some minutes\n\
+                                 /help - Show this text\n\
+                                 /set_title <arg1>");
+}
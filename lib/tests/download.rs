@@ -0,0 +1,40 @@
+extern crate telegram_bot;
+extern crate tokio_core;
+
+use tokio_core::reactor::Core;
+
+use telegram_bot::{Api, Error, ErrorKind, File};
+
+#[test]
+fn download_file_too_large() {
+    let mut core = Core::new().unwrap();
+    let api = Api::configure("token").build(core.handle()).unwrap();
+
+    let file = File {
+        file_id: "file_id".to_string(),
+        file_size: Some(21 * 1024 * 1024),
+        file_path: Some("documents/file.bin".to_string()),
+    };
+
+    match core.run(api.download_file(&file)) {
+        Err(Error(ErrorKind::FileTooLarge(size), _)) => assert_eq!(size, 21 * 1024 * 1024),
+        result => panic!("unexpected result {:?}", result.map(|data| data.len())),
+    }
+}
+
+#[test]
+fn download_file_without_path() {
+    let mut core = Core::new().unwrap();
+    let api = Api::configure("token").build(core.handle()).unwrap();
+
+    let file = File {
+        file_id: "file_id".to_string(),
+        file_size: None,
+        file_path: None,
+    };
+
+    match core.run(api.download_file(&file)) {
+        Err(Error(ErrorKind::FileUnavailable, _)) => (),
+        result => panic!("unexpected result {:?}", result.map(|data| data.len())),
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "webhook")]
+
+extern crate futures;
+extern crate hyper;
+extern crate telegram_bot;
+extern crate tokio_core;
+
+use std::io::prelude::*;
+use std::fs::File;
+
+use futures::{Future, Stream};
+use hyper::{Client, Method, Request, StatusCode};
+use tokio_core::reactor::Core;
+
+use telegram_bot::{WebhookServer, UpdateKind, MessageKind};
+
+fn read_asset(name: &str) -> Vec<u8> {
+    let filename = format!("../raw/tests/update_assets/{}.json", name);
+    let mut data = Vec::new();
+    let mut file = File::open(filename).unwrap();
+    file.read_to_end(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn webhook_receives_update() {
+    let mut core = Core::new().unwrap();
+    let handle = core.handle();
+
+    let server = WebhookServer::bind(&"127.0.0.1:0".parse().unwrap(), "/secret", &handle).unwrap();
+    let uri = format!("http://{}/secret", server.local_addr()).parse().unwrap();
+
+    let mut request = Request::new(Method::Post, uri);
+    request.set_body(read_asset("migrate_to_chat_id"));
+
+    let client = Client::new(&handle);
+    let response = client.request(request).map(|response| response.status()).map_err(|_| ());
+    let update = server.into_future().map(|(update, _)| update).map_err(|_| ());
+
+    let (status, update) = core.run(response.join(update)).unwrap();
+
+    assert_eq!(status, StatusCode::Ok);
+    match update.unwrap().kind {
+        UpdateKind::Message(message) => match message.kind {
+            MessageKind::MigrateToChatId { .. } => (),
+            kind => panic!("unexpected message kind {:?}", kind),
+        },
+        kind => panic!("unexpected update kind {:?}", kind),
+    }
+}
+
+#[test]
+fn webhook_rejects_unknown_path() {
+    let mut core = Core::new().unwrap();
+    let handle = core.handle();
+
+    let server = WebhookServer::bind(&"127.0.0.1:0".parse().unwrap(), "/secret", &handle).unwrap();
+    let uri = format!("http://{}/other", server.local_addr()).parse().unwrap();
+
+    let mut request = Request::new(Method::Post, uri);
+    request.set_body(read_asset("migrate_to_chat_id"));
+
+    let client = Client::new(&handle);
+    let status = core.run(client.request(request).map(|response| response.status())).unwrap();
+
+    assert_eq!(status, StatusCode::NotFound);
+}
+
+#[test]
+fn webhook_accepts_invalid_update() {
+    let mut core = Core::new().unwrap();
+    let handle = core.handle();
+
+    let server = WebhookServer::bind(&"127.0.0.1:0".parse().unwrap(), "/secret", &handle).unwrap();
+    let uri = format!("http://{}/secret", server.local_addr()).parse().unwrap();
+
+    let mut request = Request::new(Method::Post, uri);
+    request.set_body("{\"update_id\": \"invalid\"}");
+
+    let client = Client::new(&handle);
+    let response = client.request(request).map(|response| response.status()).map_err(|_| ());
+    let update = server.into_future().then(|result| Ok::<_, ()>(result.is_err()));
+
+    let (status, failed) = core.run(response.join(update)).unwrap();
+
+    assert_eq!(status, StatusCode::Ok);
+    assert!(failed);
+}
+
+#[test]
+fn webhook_rejects_large_body() {
+    let mut core = Core::new().unwrap();
+    let handle = core.handle();
+
+    let server = WebhookServer::bind(&"127.0.0.1:0".parse().unwrap(), "/secret", &handle).unwrap();
+    let uri = format!("http://{}/secret", server.local_addr()).parse().unwrap();
+
+    let mut request = Request::new(Method::Post, uri);
+    request.set_body(vec![b' '; 1024 * 1024 + 1]);
+
+    let client = Client::new(&handle);
+    let status = core.run(client.request(request).map(|response| response.status())).unwrap();
+
+    assert_eq!(status, StatusCode::PayloadTooLarge);
+}
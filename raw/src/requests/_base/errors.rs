@@ -8,9 +8,14 @@ error_chain! {
     errors {
         EmptyBody
         TelegramError {
+            error_code: Option<Integer>,
             description: String,
             parameters: Option<ResponseParameters>
         }
         DetachedError(err: String)
+        InvalidMediaGroupSize(size: usize) {
+            description("invalid media group size")
+            display("media group must contain from 2 to 10 items, got {}", size)
+        }
     }
 }
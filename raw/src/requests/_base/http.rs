@@ -1,8 +1,14 @@
-use url::TELEGRAM_URL;
+use std::path::PathBuf;
+
+use serde_json::{self, Value};
+
+use types::*;
+use url::BaseUrl;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum RequestUrl {
     Method(&'static str),
+    File(String),
 }
 
 impl RequestUrl {
@@ -10,9 +16,15 @@ impl RequestUrl {
         RequestUrl::Method(method)
     }
 
-    pub fn url(&self, token: &str) -> String {
+    /// Url of the file returned by the `getFile` method.
+    pub fn file<P>(file_path: P) -> Self where P: Into<String> {
+        RequestUrl::File(file_path.into())
+    }
+
+    pub fn url(&self, base_url: &BaseUrl, token: &str) -> String {
         match self {
-            &RequestUrl::Method(method) => format!("{}bot{}/{}", TELEGRAM_URL, token, method),
+            &RequestUrl::Method(method) => base_url.method_url(token, method),
+            &RequestUrl::File(ref path) => base_url.file_url(token, path),
         }
     }
 }
@@ -27,10 +39,33 @@ pub enum Method {
 pub enum Body {
     Empty,
     Json(Vec<u8>),
+    Multipart(Multipart),
     #[doc(hidden)]
     __Nonexhaustive,
 }
 
+/// Fields of a `multipart/form-data` request body.
+pub type Multipart = Vec<(String, MultipartValue)>;
+
+/// Value of a `multipart/form-data` field.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum MultipartValue {
+    /// Plain text value.
+    Text(String),
+    /// File which should be read from the file system.
+    Path {
+        path: PathBuf,
+        file_name: Option<String>,
+        mime: Option<String>,
+    },
+    /// File with contents in memory.
+    Data {
+        file_name: String,
+        mime: Option<String>,
+        data: Vec<u8>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct HttpRequest {
     pub url: RequestUrl,
@@ -38,6 +73,70 @@ pub struct HttpRequest {
     pub body: Body,
 }
 
+impl HttpRequest {
+    /// Target chat of the request taken from the `chat_id` parameter, if any.
+    pub fn chat_ref(&self) -> Option<ChatRef> {
+        match self.body {
+            Body::Json(ref body) => {
+                let body: Value = serde_json::from_slice(body).ok()?;
+                match *body.get("chat_id")? {
+                    Value::Number(ref id) => id.as_i64().map(|id| ChatId::new(id).to_chat_ref()),
+                    Value::String(ref username) => Some(ChatRef::ChannelUsername(username.clone())),
+                    _ => None,
+                }
+            }
+            Body::Multipart(ref parts) => parts.iter().filter_map(|&(ref name, ref value)| {
+                match (name.as_str(), value) {
+                    ("chat_id", &MultipartValue::Text(ref text)) => Some(match text.parse() {
+                        Ok(id) => ChatId::new(id).to_chat_ref(),
+                        Err(_) => ChatRef::ChannelUsername(text.clone()),
+                    }),
+                    _ => None,
+                }
+            }).next(),
+            _ => None,
+        }
+    }
+
+    /// Replace the `chat_id` parameter of the request with `chat`.
+    /// Returns `false` if the request has no such parameter.
+    pub fn set_chat_ref(&mut self, chat: &ChatRef) -> bool {
+        match self.body {
+            Body::Json(ref mut body) => {
+                let mut value: Value = match serde_json::from_slice(body) {
+                    Ok(value) => value,
+                    Err(_) => return false,
+                };
+                match value.get_mut("chat_id") {
+                    Some(chat_id) => *chat_id = match *chat {
+                        ChatRef::Id(id) => Value::from(Integer::from(id)),
+                        ChatRef::ChannelUsername(ref username) => Value::from(username.clone()),
+                    },
+                    None => return false,
+                }
+                match serde_json::to_vec(&value) {
+                    Ok(value) => *body = value,
+                    Err(_) => return false,
+                }
+                true
+            }
+            Body::Multipart(ref mut parts) => {
+                for &mut (ref name, ref mut value) in parts.iter_mut() {
+                    if name == "chat_id" {
+                        *value = MultipartValue::Text(match *chat {
+                            ChatRef::Id(id) => Integer::from(id).to_string(),
+                            ChatRef::ChannelUsername(ref username) => username.clone(),
+                        });
+                        return true
+                    }
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct HttpResponse {
     pub body: Option<Vec<u8>>
@@ -5,7 +5,7 @@ mod errors;
 pub use self::errors::{Error, ErrorKind};
 
 mod http;
-pub use self::http::{RequestUrl, Body};
+pub use self::http::{RequestUrl, Body, Multipart, MultipartValue};
 pub use self::http::{Method, HttpRequest, HttpResponse};
 
 mod request_types;
@@ -1,5 +1,8 @@
 mod json;
 pub use self::json::*;
 
+mod multipart;
+pub use self::multipart::*;
+
 mod detached;
 pub use self::detached::*;
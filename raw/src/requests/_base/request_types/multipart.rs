@@ -0,0 +1,51 @@
+use serde::Serialize;
+use serde_json::{self, Value};
+
+use requests::*;
+use types::*;
+
+/// Request which may contain files to upload.
+pub trait ToMultipart: Serialize {
+    /// Files which should be uploaded with the request, keyed by field name.
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)>;
+}
+
+/// Request is serialized as JSON unless there are files to upload,
+/// in which case `multipart/form-data` is used.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct MultipartRequestType<Request> {
+    phantom: ::std::marker::PhantomData<Request>,
+}
+
+impl<Request: ToMultipart> RequestType for MultipartRequestType<Request> {
+    type Options = RequestUrl;
+    type Request = Request;
+
+    fn serialize(url: Self::Options, request: &Self::Request) -> Result<HttpRequest, Error> {
+        let uploads = request.uploads();
+        if uploads.is_empty() {
+            return <JsonRequestType<Request> as RequestType>::serialize(url, request)
+        }
+
+        let mut multipart = Multipart::new();
+        if let Value::Object(fields) = serde_json::to_value(request)? {
+            for (name, value) in fields {
+                match value {
+                    Value::Null => (),
+                    Value::String(text) => multipart.push((name, MultipartValue::Text(text))),
+                    value => multipart.push((name, MultipartValue::Text(value.to_string()))),
+                }
+            }
+        }
+
+        for (name, upload) in uploads {
+            multipart.push((name, upload.to_multipart_value()));
+        }
+
+        Ok(HttpRequest {
+            url: url,
+            method: Method::Post,
+            body: Body::Multipart(multipart),
+        })
+    }
+}
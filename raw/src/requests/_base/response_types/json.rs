@@ -45,8 +45,9 @@ impl<Resp: JsonResponse> ResponseType for Resp where <Resp as JsonResponse>::Raw
                 ResponseWrapper::Success {result} => {
                     Ok(<Self as JsonResponse>::map(result))
                 },
-                ResponseWrapper::Error { description, parameters } => {
+                ResponseWrapper::Error { error_code, description, parameters } => {
                     Err(ErrorKind::TelegramError {
+                        error_code: error_code,
                         description: description,
                         parameters: parameters
                     }.into())
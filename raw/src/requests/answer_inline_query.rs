@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+use std::ops::Not;
+
+use types::*;
+use requests::*;
+
+/// Use this method to send answers to an inline query.
+/// No more than 50 results per query are allowed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct AnswerInlineQuery<'t> {
+    inline_query_id: InlineQueryId,
+    results: Vec<InlineQueryResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cache_time: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    is_personal: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_offset: Option<Cow<'t, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    switch_pm_text: Option<Cow<'t, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    switch_pm_parameter: Option<Cow<'t, str>>,
+}
+
+impl<'t> Request for AnswerInlineQuery<'t> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("answerInlineQuery"), self)
+    }
+}
+
+impl<'t> AnswerInlineQuery<'t> {
+    pub fn new<Q, I>(query: Q, results: I) -> Self
+    where
+        Q: ToInlineQueryId,
+        I: IntoIterator,
+        I::Item: Into<InlineQueryResult>,
+    {
+        Self {
+            inline_query_id: query.to_inline_query_id(),
+            results: results.into_iter().map(Into::into).collect(),
+            cache_time: None,
+            is_personal: false,
+            next_offset: None,
+            switch_pm_text: None,
+            switch_pm_parameter: None,
+        }
+    }
+
+    /// Add a result to the answer.
+    pub fn add_result<R>(&mut self, result: R) -> &mut Self
+    where
+        R: Into<InlineQueryResult>,
+    {
+        self.results.push(result.into());
+        self
+    }
+
+    /// The maximum amount of time in seconds that the result of the inline query
+    /// may be cached on the server. Defaults to 300.
+    pub fn cache_time(&mut self, time: Integer) -> &mut Self {
+        self.cache_time = Some(time);
+        self
+    }
+
+    /// Results may be cached on the server side only for the user that sent the query.
+    /// By default, results may be returned to any user who sends the same query.
+    pub fn is_personal(&mut self) -> &mut Self {
+        self.is_personal = true;
+        self
+    }
+
+    /// Offset that a client should send in the next query with the same text
+    /// to receive more results. Pass an empty string if there are no more results
+    /// or if you don‘t support pagination. Offset length can’t exceed 64 bytes.
+    pub fn next_offset<T>(&mut self, offset: T) -> &mut Self
+    where
+        T: Into<Cow<'t, str>>,
+    {
+        self.next_offset = Some(offset.into());
+        self
+    }
+
+    /// Clients will display a button with `text` that switches the user to a private
+    /// chat with the bot and sends the bot a start message with the `parameter`.
+    pub fn switch_pm<T, P>(&mut self, text: T, parameter: P) -> &mut Self
+    where
+        T: Into<Cow<'t, str>>,
+        P: Into<Cow<'t, str>>,
+    {
+        self.switch_pm_text = Some(text.into());
+        self.switch_pm_parameter = Some(parameter.into());
+        self
+    }
+}
+
+/// Send answers to an inline query.
+pub trait CanAnswerInlineQuery {
+    fn answer<'t, I>(&self, results: I) -> AnswerInlineQuery<'t>
+    where
+        I: IntoIterator,
+        I::Item: Into<InlineQueryResult>;
+}
+
+impl<Q> CanAnswerInlineQuery for Q
+where
+    Q: ToInlineQueryId,
+{
+    fn answer<'t, I>(&self, results: I) -> AnswerInlineQuery<'t>
+    where
+        I: IntoIterator,
+        I::Item: Into<InlineQueryResult>,
+    {
+        AnswerInlineQuery::new(&self, results)
+    }
+}
@@ -0,0 +1,39 @@
+use types::*;
+use requests::*;
+
+/// Use this method to delete a group sticker set from a supergroup.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct DeleteChatStickerSet {
+    chat_id: ChatRef
+}
+
+impl Request for DeleteChatStickerSet {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("deleteChatStickerSet"), self)
+    }
+}
+
+impl DeleteChatStickerSet {
+    pub fn new<C>(chat: C) -> Self where C: ToChatRef {
+        DeleteChatStickerSet {
+            chat_id: chat.to_chat_ref()
+        }
+    }
+}
+
+/// Delete a group sticker set from a supergroup.
+pub trait CanDeleteChatStickerSet {
+    fn delete_sticker_set(&self) -> DeleteChatStickerSet;
+}
+
+impl<C> CanDeleteChatStickerSet for C where C: ToChatRef {
+    fn delete_sticker_set(&self) -> DeleteChatStickerSet {
+        DeleteChatStickerSet::new(self)
+    }
+}
@@ -0,0 +1,39 @@
+use types::*;
+use requests::*;
+
+/// Use this method to generate a new invite link for a chat; any previously
+/// generated link is revoked. The bot must be an administrator in the chat
+/// for this to work and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ExportChatInviteLink {
+    chat_id: ChatRef
+}
+
+impl Request for ExportChatInviteLink {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonIdResponse<String>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("exportChatInviteLink"), self)
+    }
+}
+
+impl ExportChatInviteLink {
+    pub fn new<C>(chat: C) -> Self where C: ToChatRef {
+        ExportChatInviteLink {
+            chat_id: chat.to_chat_ref()
+        }
+    }
+}
+
+/// Generate a new invite link for a chat.
+pub trait CanExportChatInviteLink {
+    fn export_invite_link(&self) -> ExportChatInviteLink;
+}
+
+impl<C> CanExportChatInviteLink for C where C: ToChatRef {
+    fn export_invite_link(&self) -> ExportChatInviteLink {
+        ExportChatInviteLink::new(self)
+    }
+}
@@ -1,3 +1,9 @@
+use std::fmt;
+
+use serde::de;
+use serde::de::{Deserialize, Deserializer, Visitor};
+use serde::ser::Serializer;
+
 use requests::*;
 use types::*;
 
@@ -11,6 +17,7 @@ pub struct GetUpdates {
     limit: Option<Integer>, // TODO(knsd): Values between 1—100 are accepted
     #[serde(skip_serializing_if = "Option::is_none")]
     timeout: Option<Integer>, // TODO(knsd): Should be positive
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     allowed_updates: Vec<AllowedUpdate> // TODO(knsd) BitSet? HashSet? BTreeSet?
 }
 
@@ -54,20 +61,79 @@ impl GetUpdates {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+/// Kind of updates the bot receives.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AllowedUpdate {
-    #[serde(rename="message")]
     Message,
-    #[serde(rename="edited_message")]
     EditedMessage,
-    #[serde(rename="channel_post")]
     ChannelPost,
-    #[serde(rename="edited_channel_post")]
     EditedChannelPost,
-//    #[serde(rename="inline_query")]
-//    InlineQuery,
-//    #[serde(rename="chosen_inline_query")]
-//    ChosenInlineResult,
-//    #[serde(rename="callback_query")]
-//    CallbackQuery,
+    InlineQuery,
+    ChosenInlineResult,
+    CallbackQuery,
+    ShippingQuery,
+    PreCheckoutQuery,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl AllowedUpdate {
+    fn as_str(&self) -> &str {
+        use self::AllowedUpdate::*;
+
+        match *self {
+            Message => "message",
+            EditedMessage => "edited_message",
+            ChannelPost => "channel_post",
+            EditedChannelPost => "edited_channel_post",
+            InlineQuery => "inline_query",
+            ChosenInlineResult => "chosen_inline_result",
+            CallbackQuery => "callback_query",
+            ShippingQuery => "shipping_query",
+            PreCheckoutQuery => "pre_checkout_query",
+            Unknown(ref kind) => kind,
+        }
+    }
+}
+
+impl ::serde::Serialize for AllowedUpdate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowedUpdate {
+    fn deserialize<D>(deserializer: D) -> Result<AllowedUpdate, D::Error>
+        where D: Deserializer<'de>
+    {
+        struct AllowedUpdateVisitor;
+        use self::AllowedUpdate::*;
+
+        impl<'de> Visitor<'de> for AllowedUpdateVisitor {
+            type Value = AllowedUpdate;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("kind of update")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<AllowedUpdate, E>
+                where E: de::Error
+            {
+                Ok(match value {
+                    "message" => Message,
+                    "edited_message" => EditedMessage,
+                    "channel_post" => ChannelPost,
+                    "edited_channel_post" => EditedChannelPost,
+                    "inline_query" => InlineQuery,
+                    "chosen_inline_result" => ChosenInlineResult,
+                    "callback_query" => CallbackQuery,
+                    "shipping_query" => ShippingQuery,
+                    "pre_checkout_query" => PreCheckoutQuery,
+                    _unknown => Unknown(value.to_string()),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(AllowedUpdateVisitor)
+    }
 }
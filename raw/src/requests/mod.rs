@@ -1,10 +1,15 @@
 pub mod _base;
 pub mod answer_callback_query;
+pub mod answer_inline_query;
+pub mod delete_chat_photo;
+pub mod delete_chat_sticker_set;
 pub mod delete_message;
+pub mod delete_webhook;
 pub mod edit_message_caption;
 pub mod edit_message_live_location;
 pub mod edit_message_reply_markup;
 pub mod edit_message_text;
+pub mod export_chat_invite_link;
 pub mod forward_message;
 pub mod get_chat;
 pub mod get_chat_administrators;
@@ -14,26 +19,47 @@ pub mod get_file;
 pub mod get_me;
 pub mod get_updates;
 pub mod get_user_profile_photos;
+pub mod get_webhook_info;
 pub mod kick_chat_member;
 pub mod leave_chat;
 pub mod pin_chat_message;
+pub mod promote_chat_member;
+pub mod restrict_chat_member;
 pub mod send_chat_action;
 pub mod send_contact;
 pub mod send_location;
+pub mod send_media_group;
 pub mod send_message;
 pub mod send_venue;
 pub mod send_audio;
+pub mod send_animation;
+pub mod send_document;
+pub mod send_photo;
+pub mod send_sticker;
+pub mod send_video;
+pub mod send_video_note;
+pub mod send_voice;
+pub mod set_chat_description;
+pub mod set_chat_photo;
+pub mod set_chat_sticker_set;
+pub mod set_chat_title;
+pub mod set_webhook;
 pub mod stop_message_live_location;
 pub mod unban_chat_member;
 pub mod unpin_chat_message;
 
 pub use self::_base::*;
 pub use self::answer_callback_query::*;
+pub use self::answer_inline_query::*;
+pub use self::delete_chat_photo::*;
+pub use self::delete_chat_sticker_set::*;
 pub use self::delete_message::*;
+pub use self::delete_webhook::*;
 pub use self::edit_message_caption::*;
 pub use self::edit_message_live_location::*;
 pub use self::edit_message_reply_markup::*;
 pub use self::edit_message_text::*;
+pub use self::export_chat_invite_link::*;
 pub use self::forward_message::*;
 pub use self::get_chat::*;
 pub use self::get_chat_administrators::*;
@@ -43,15 +69,31 @@ pub use self::get_file::*;
 pub use self::get_me::*;
 pub use self::get_updates::*;
 pub use self::get_user_profile_photos::*;
+pub use self::get_webhook_info::*;
 pub use self::kick_chat_member::*;
 pub use self::leave_chat::*;
 pub use self::pin_chat_message::*;
+pub use self::promote_chat_member::*;
+pub use self::restrict_chat_member::*;
 pub use self::send_chat_action::*;
 pub use self::send_contact::*;
 pub use self::send_location::*;
+pub use self::send_media_group::*;
 pub use self::send_message::*;
 pub use self::send_venue::*;
 pub use self::send_audio::*;
+pub use self::send_animation::*;
+pub use self::send_document::*;
+pub use self::send_photo::*;
+pub use self::send_sticker::*;
+pub use self::send_video::*;
+pub use self::send_video_note::*;
+pub use self::send_voice::*;
+pub use self::set_chat_description::*;
+pub use self::set_chat_photo::*;
+pub use self::set_chat_sticker_set::*;
+pub use self::set_chat_title::*;
+pub use self::set_webhook::*;
 pub use self::stop_message_live_location::*;
 pub use self::unban_chat_member::*;
 pub use self::unpin_chat_message::*;
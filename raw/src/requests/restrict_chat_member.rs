@@ -0,0 +1,81 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use types::*;
+use requests::*;
+
+/// Use this method to restrict a user in a supergroup.
+/// The bot must be an administrator in the supergroup for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct RestrictChatMember {
+    chat_id: ChatRef,
+    user_id: UserId,
+    permissions: ChatPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until_date: Option<Integer>,
+}
+
+impl Request for RestrictChatMember {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("restrictChatMember"), self)
+    }
+}
+
+impl RestrictChatMember {
+    pub fn new<C, U>(chat: C, user: U, permissions: ChatPermissions) -> Self
+        where C: ToChatRef, U: ToUserId
+    {
+        RestrictChatMember {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            permissions: permissions,
+            until_date: None,
+        }
+    }
+
+    /// Date when restrictions will be lifted for the user, unix time.
+    /// If user is restricted for more than 366 days or less than 30 seconds
+    /// from the current time, they are considered to be restricted forever.
+    pub fn until_date(&mut self, date: Integer) -> &mut Self {
+        self.until_date = Some(date);
+        self
+    }
+
+    /// Lift restrictions after `duration` counting from now.
+    pub fn duration(&mut self, duration: Duration) -> &mut Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        self.until_date((now + duration).as_secs() as Integer)
+    }
+}
+
+/// Restrict a user in a supergroup.
+pub trait CanRestrictChatMemberForChat {
+    fn restrict<O>(&self, other: O, permissions: ChatPermissions) -> RestrictChatMember
+        where O: ToUserId;
+}
+
+impl<C> CanRestrictChatMemberForChat for C where C: ToChatRef {
+    fn restrict<O>(&self, other: O, permissions: ChatPermissions) -> RestrictChatMember
+        where O: ToUserId
+    {
+        RestrictChatMember::new(self, other, permissions)
+    }
+}
+
+/// Restrict a user in a supergroup.
+pub trait CanRestrictChatMemberForUser {
+    fn restrict_in<O>(&self, other: O, permissions: ChatPermissions) -> RestrictChatMember
+        where O: ToChatRef;
+}
+
+impl<U> CanRestrictChatMemberForUser for U where U: ToUserId {
+    fn restrict_in<O>(&self, other: O, permissions: ChatPermissions) -> RestrictChatMember
+        where O: ToChatRef
+    {
+        RestrictChatMember::new(other, self, permissions)
+    }
+}
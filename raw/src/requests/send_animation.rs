@@ -0,0 +1,185 @@
+use std::ops::Not;
+use std::borrow::Cow;
+use std::path::Path;
+
+use types::*;
+use requests::*;
+
+/// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendAnimation<'c> {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    animation: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<Cow<'c, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> Request for SendAnimation<'c> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendAnimation"), self)
+    }
+}
+
+impl<'c> ToMultipart for SendAnimation<'c> {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.animation.upload().map(|upload| ("animation".to_string(), upload)).into_iter().collect()
+    }
+}
+
+impl<'c> SendAnimation<'c> {
+    pub fn new<C, F>(chat: C, animation: F) -> Self
+    where
+        C: ToChatRef,
+        F: Into<InputFile>,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            animation: animation.into(),
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            width: None,
+            height: None,
+            reply_to_message_id: None,
+            disable_notification: false,
+            reply_markup: None,
+        }
+    }
+
+    pub fn with_url<C, T>(chat: C, url: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+    {
+        Self::new(chat, InputFile::url(url))
+    }
+
+    /// Upload an animation from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self
+    where
+        C: ToChatRef,
+        P: AsRef<Path>,
+    {
+        Self::new(chat, InputFile::path(path))
+    }
+
+    /// Upload an animation with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        Self::new(chat, InputFile::bytes(file_name, data))
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Duration of sent animation in seconds.
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Animation width.
+    pub fn width(&mut self, width: Integer) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Animation height.
+    pub fn height(&mut self, height: Integer) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Reply with an animation.
+pub trait CanReplySendAnimation {
+    fn animation_reply<'c, F>(&self, animation: F) -> SendAnimation<'c>
+    where
+        F: Into<InputFile>;
+}
+
+impl<M> CanReplySendAnimation for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn animation_reply<'c, F>(&self, animation: F) -> SendAnimation<'c>
+    where
+        F: Into<InputFile>,
+    {
+        let mut req = SendAnimation::new(self.to_source_chat(), animation);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send an animation.
+pub trait CanSendAnimation {
+    fn animation<'c, F>(&self, animation: F) -> SendAnimation<'c>
+    where
+        F: Into<InputFile>;
+}
+
+impl<C> CanSendAnimation for C
+where
+    C: ToChatRef,
+{
+    fn animation<'c, F>(&self, animation: F) -> SendAnimation<'c>
+    where
+        F: Into<InputFile>,
+    {
+        SendAnimation::new(self.to_chat_ref(), animation)
+    }
+}
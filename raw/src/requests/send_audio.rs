@@ -1,5 +1,6 @@
 use std::ops::Not;
 use std::borrow::Cow;
+use std::path::Path;
 
 use types::*;
 use requests::*;
@@ -7,9 +8,10 @@ use requests::*;
 /// Use this method to send an audio
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
 #[must_use = "requests do nothing unless sent"]
-pub struct SendAudio<'s, 'c, 'p, 't> {
+pub struct SendAudio<'c, 'p, 't> {
     chat_id: ChatRef,
-    audio: Cow<'s, str>,
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    audio: InputFile,
     #[serde(skip_serializing_if = "Option::is_none")]
     caption: Option<Cow<'c, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,24 +30,30 @@ pub struct SendAudio<'s, 'c, 'p, 't> {
     reply_markup: Option<ReplyMarkup>,
 }
 
-impl<'s, 'c, 'p, 't> Request for SendAudio<'s, 'c, 'p, 't> {
-    type Type = JsonRequestType<Self>;
-    type Response = JsonTrueToUnitResponse;
+impl<'c, 'p, 't> Request for SendAudio<'c, 'p, 't> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
 
     fn serialize(&self) -> Result<HttpRequest, Error> {
         Self::Type::serialize(RequestUrl::method("sendAudio"), self)
     }
 }
 
-impl<'s, 'c, 'p, 't> SendAudio<'s, 'c, 'p, 't> {
-    pub fn with_url<C, T>(chat: C, url: T) -> Self
+impl<'c, 'p, 't> ToMultipart for SendAudio<'c, 'p, 't> {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.audio.upload().map(|upload| ("audio".to_string(), upload)).into_iter().collect()
+    }
+}
+
+impl<'c, 'p, 't> SendAudio<'c, 'p, 't> {
+    pub fn new<C, F>(chat: C, audio: F) -> Self
     where
         C: ToChatRef,
-        T: Into<Cow<'s, str>>,
+        F: Into<InputFile>,
     {
         Self {
             chat_id: chat.to_chat_ref(),
-            audio: url.into(),
+            audio: audio.into(),
             caption: None,
             parse_mode: None,
             duration: None,
@@ -57,6 +65,32 @@ impl<'s, 'c, 'p, 't> SendAudio<'s, 'c, 'p, 't> {
         }
     }
 
+    pub fn with_url<C, T>(chat: C, url: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+    {
+        Self::new(chat, InputFile::url(url))
+    }
+
+    /// Upload an audio file from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self
+    where
+        C: ToChatRef,
+        P: AsRef<Path>,
+    {
+        Self::new(chat, InputFile::path(path))
+    }
+
+    /// Upload an audio file with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        Self::new(chat, InputFile::bytes(file_name, data))
+    }
+
     pub fn caption<T>(&mut self, caption: T) -> &mut Self
     where
         T: Into<Cow<'c, str>>,
@@ -99,6 +133,11 @@ impl<'s, 'c, 'p, 't> SendAudio<'s, 'c, 'p, 't> {
         self
     }
 
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
     pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
     where
         R: Into<ReplyMarkup>,
@@ -110,18 +149,31 @@ impl<'s, 'c, 'p, 't> SendAudio<'s, 'c, 'p, 't> {
 
 /// Can reply with an audio
 pub trait CanReplySendAudio {
-    fn audio_url_reply<'s, 'c, 'p, 't, T>(&self, url: T) -> SendAudio<'s, 'c, 'p, 't>
+    fn audio_reply<'c, 'p, 't, F>(&self, audio: F) -> SendAudio<'c, 'p, 't>
     where
-        T: Into<Cow<'s, str>>;
+        F: Into<InputFile>;
+
+    fn audio_url_reply<'c, 'p, 't, T>(&self, url: T) -> SendAudio<'c, 'p, 't>
+    where
+        T: Into<String>;
 }
 
 impl<M> CanReplySendAudio for M
 where
     M: ToMessageId + ToSourceChat,
 {
-    fn audio_url_reply<'s, 'c, 'p, 't, T>(&self, url: T) -> SendAudio<'s, 'c, 'p, 't>
+    fn audio_reply<'c, 'p, 't, F>(&self, audio: F) -> SendAudio<'c, 'p, 't>
+    where
+        F: Into<InputFile>,
+    {
+        let mut req = SendAudio::new(self.to_source_chat(), audio);
+        req.reply_to(self);
+        req
+    }
+
+    fn audio_url_reply<'c, 'p, 't, T>(&self, url: T) -> SendAudio<'c, 'p, 't>
     where
-        T: Into<Cow<'s, str>>,
+        T: Into<String>,
     {
         let mut req = SendAudio::with_url(self.to_source_chat(), url);
         req.reply_to(self);
@@ -131,18 +183,29 @@ where
 
 /// Send an audio
 pub trait CanSendAudio {
-    fn audio_url<'s, 'c, 'p, 't, T>(&self, url: T) -> SendAudio<'s, 'c, 'p, 't>
+    fn audio<'c, 'p, 't, F>(&self, audio: F) -> SendAudio<'c, 'p, 't>
+    where
+        F: Into<InputFile>;
+
+    fn audio_url<'c, 'p, 't, T>(&self, url: T) -> SendAudio<'c, 'p, 't>
     where
-        T: Into<Cow<'s, str>>;
+        T: Into<String>;
 }
 
 impl<M> CanSendAudio for M
 where
     M: ToChatRef,
 {
-    fn audio_url<'s, 'c, 'p, 't, T>(&self, url: T) -> SendAudio<'s, 'c, 'p, 't>
+    fn audio<'c, 'p, 't, F>(&self, audio: F) -> SendAudio<'c, 'p, 't>
+    where
+        F: Into<InputFile>,
+    {
+        SendAudio::new(self.to_chat_ref(), audio)
+    }
+
+    fn audio_url<'c, 'p, 't, T>(&self, url: T) -> SendAudio<'c, 'p, 't>
     where
-        T: Into<Cow<'s, str>>,
+        T: Into<String>,
     {
         SendAudio::with_url(self.to_chat_ref(), url)
     }
@@ -0,0 +1,149 @@
+use std::ops::Not;
+
+use serde::ser::{Error as SerError, SerializeSeq, Serializer};
+use serde_json::{self, Value};
+
+use types::*;
+use requests::*;
+
+/// Use this method to send a group of photos or videos as an album.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendMediaGroup {
+    chat_id: ChatRef,
+    #[serde(serialize_with = "serialize_media")]
+    media: Vec<InputMedia>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+}
+
+const MEDIA_GROUP_MIN_SIZE: usize = 2;
+const MEDIA_GROUP_MAX_SIZE: usize = 10;
+
+impl Request for SendMediaGroup {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Vec<Message>>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        let size = self.media.len();
+        if size < MEDIA_GROUP_MIN_SIZE || size > MEDIA_GROUP_MAX_SIZE {
+            return Err(ErrorKind::InvalidMediaGroupSize(size).into())
+        }
+        Self::Type::serialize(RequestUrl::method("sendMediaGroup"), self)
+    }
+}
+
+impl ToMultipart for SendMediaGroup {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.media.iter().enumerate().filter_map(|(index, media)| {
+            media.media().upload().map(|upload| (attach_name(index), upload))
+        }).collect()
+    }
+}
+
+fn attach_name(index: usize) -> String {
+    format!("media{}", index)
+}
+
+/// Uploaded files are referenced as `attach://<name>` where `<name>`
+/// is the name of the corresponding `multipart/form-data` part.
+fn serialize_media<S>(media: &Vec<InputMedia>, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+{
+    let mut seq = serializer.serialize_seq(Some(media.len()))?;
+    for (index, item) in media.iter().enumerate() {
+        let mut value = serde_json::to_value(item).map_err(S::Error::custom)?;
+        if item.media().is_upload() {
+            if let Value::Object(ref mut fields) = value {
+                let attach = format!("attach://{}", attach_name(index));
+                fields.insert("media".to_string(), Value::String(attach));
+            }
+        }
+        seq.serialize_element(&value)?;
+    }
+    seq.end()
+}
+
+impl SendMediaGroup {
+    pub fn new<C, I>(chat: C, media: I) -> Self
+    where
+        C: ToChatRef,
+        I: IntoIterator,
+        I::Item: Into<InputMedia>,
+    {
+        SendMediaGroup {
+            chat_id: chat.to_chat_ref(),
+            media: media.into_iter().map(Into::into).collect(),
+            reply_to_message_id: None,
+            disable_notification: false,
+        }
+    }
+
+    /// Add an item to the album.
+    pub fn add_media<M>(&mut self, media: M) -> &mut Self
+    where
+        M: Into<InputMedia>,
+    {
+        self.media.push(media.into());
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+}
+
+/// Reply with an album.
+pub trait CanReplySendMediaGroup {
+    fn media_group_reply<I>(&self, media: I) -> SendMediaGroup
+    where
+        I: IntoIterator,
+        I::Item: Into<InputMedia>;
+}
+
+impl<M> CanReplySendMediaGroup for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn media_group_reply<I>(&self, media: I) -> SendMediaGroup
+    where
+        I: IntoIterator,
+        I::Item: Into<InputMedia>,
+    {
+        let mut req = SendMediaGroup::new(self.to_source_chat(), media);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send an album.
+pub trait CanSendMediaGroup {
+    fn media_group<I>(&self, media: I) -> SendMediaGroup
+    where
+        I: IntoIterator,
+        I::Item: Into<InputMedia>;
+}
+
+impl<C> CanSendMediaGroup for C
+where
+    C: ToChatRef,
+{
+    fn media_group<I>(&self, media: I) -> SendMediaGroup
+    where
+        I: IntoIterator,
+        I::Item: Into<InputMedia>,
+    {
+        SendMediaGroup::new(self.to_chat_ref(), media)
+    }
+}
@@ -0,0 +1,158 @@
+use std::ops::Not;
+use std::borrow::Cow;
+use std::path::Path;
+
+use types::*;
+use requests::*;
+
+/// Use this method to send photos.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendPhoto<'c> {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    photo: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<Cow<'c, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl<'c> Request for SendPhoto<'c> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendPhoto"), self)
+    }
+}
+
+impl<'c> ToMultipart for SendPhoto<'c> {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.photo.upload().map(|upload| ("photo".to_string(), upload)).into_iter().collect()
+    }
+}
+
+impl<'c> SendPhoto<'c> {
+    pub fn new<C, F>(chat: C, photo: F) -> Self
+    where
+        C: ToChatRef,
+        F: Into<InputFile>,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            photo: photo.into(),
+            caption: None,
+            parse_mode: None,
+            reply_to_message_id: None,
+            disable_notification: false,
+            reply_markup: None,
+        }
+    }
+
+    pub fn with_url<C, T>(chat: C, url: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+    {
+        Self::new(chat, InputFile::url(url))
+    }
+
+    /// Upload a photo from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self
+    where
+        C: ToChatRef,
+        P: AsRef<Path>,
+    {
+        Self::new(chat, InputFile::path(path))
+    }
+
+    /// Upload a photo with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        Self::new(chat, InputFile::bytes(file_name, data))
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<Cow<'c, str>>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Reply with a photo.
+pub trait CanReplySendPhoto {
+    fn photo_reply<'c, F>(&self, photo: F) -> SendPhoto<'c>
+    where
+        F: Into<InputFile>;
+}
+
+impl<M> CanReplySendPhoto for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn photo_reply<'c, F>(&self, photo: F) -> SendPhoto<'c>
+    where
+        F: Into<InputFile>,
+    {
+        let mut req = SendPhoto::new(self.to_source_chat(), photo);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a photo.
+pub trait CanSendPhoto {
+    fn photo<'c, F>(&self, photo: F) -> SendPhoto<'c>
+    where
+        F: Into<InputFile>;
+}
+
+impl<C> CanSendPhoto for C
+where
+    C: ToChatRef,
+{
+    fn photo<'c, F>(&self, photo: F) -> SendPhoto<'c>
+    where
+        F: Into<InputFile>,
+    {
+        SendPhoto::new(self.to_chat_ref(), photo)
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::Not;
+use std::path::Path;
+
+use types::*;
+use requests::*;
+
+/// Use this method to send .webp stickers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendSticker {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    sticker: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for SendSticker {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendSticker"), self)
+    }
+}
+
+impl ToMultipart for SendSticker {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.sticker.upload().map(|upload| ("sticker".to_string(), upload)).into_iter().collect()
+    }
+}
+
+impl SendSticker {
+    pub fn new<C, F>(chat: C, sticker: F) -> Self
+    where
+        C: ToChatRef,
+        F: Into<InputFile>,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            sticker: sticker.into(),
+            reply_to_message_id: None,
+            disable_notification: false,
+            reply_markup: None,
+        }
+    }
+
+    pub fn with_url<C, T>(chat: C, url: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+    {
+        Self::new(chat, InputFile::url(url))
+    }
+
+    /// Upload a sticker from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self
+    where
+        C: ToChatRef,
+        P: AsRef<Path>,
+    {
+        Self::new(chat, InputFile::path(path))
+    }
+
+    /// Upload a sticker with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        Self::new(chat, InputFile::bytes(file_name, data))
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Reply with a sticker.
+pub trait CanReplySendSticker {
+    fn sticker_reply<F>(&self, sticker: F) -> SendSticker
+    where
+        F: Into<InputFile>;
+}
+
+impl<M> CanReplySendSticker for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn sticker_reply<F>(&self, sticker: F) -> SendSticker
+    where
+        F: Into<InputFile>,
+    {
+        let mut req = SendSticker::new(self.to_source_chat(), sticker);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a sticker.
+pub trait CanSendSticker {
+    fn sticker<F>(&self, sticker: F) -> SendSticker
+    where
+        F: Into<InputFile>;
+}
+
+impl<C> CanSendSticker for C
+where
+    C: ToChatRef,
+{
+    fn sticker<F>(&self, sticker: F) -> SendSticker
+    where
+        F: Into<InputFile>,
+    {
+        SendSticker::new(self.to_chat_ref(), sticker)
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::Not;
+use std::path::Path;
+
+use types::*;
+use requests::*;
+
+/// Use this method to send video messages. As of v.4.0, Telegram clients
+/// support rounded square mp4 videos of up to 1 minute long.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SendVideoNote {
+    chat_id: ChatRef,
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    video_note: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    length: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to_message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_notification: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<ReplyMarkup>,
+}
+
+impl Request for SendVideoNote {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonIdResponse<Message>;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("sendVideoNote"), self)
+    }
+}
+
+impl ToMultipart for SendVideoNote {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.video_note.upload().map(|upload| ("video_note".to_string(), upload)).into_iter().collect()
+    }
+}
+
+impl SendVideoNote {
+    pub fn new<C, F>(chat: C, video_note: F) -> Self
+    where
+        C: ToChatRef,
+        F: Into<InputFile>,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            video_note: video_note.into(),
+            duration: None,
+            length: None,
+            reply_to_message_id: None,
+            disable_notification: false,
+            reply_markup: None,
+        }
+    }
+
+    pub fn with_url<C, T>(chat: C, url: T) -> Self
+    where
+        C: ToChatRef,
+        T: Into<String>,
+    {
+        Self::new(chat, InputFile::url(url))
+    }
+
+    /// Upload a video message from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self
+    where
+        C: ToChatRef,
+        P: AsRef<Path>,
+    {
+        Self::new(chat, InputFile::path(path))
+    }
+
+    /// Upload a video message with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+    where
+        C: ToChatRef,
+        N: Into<String>,
+    {
+        Self::new(chat, InputFile::bytes(file_name, data))
+    }
+
+    /// Duration of sent video in seconds.
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Video width and height, i.e. diameter of the video message.
+    pub fn length(&mut self, length: Integer) -> &mut Self {
+        self.length = Some(length);
+        self
+    }
+
+    pub fn reply_to<R>(&mut self, to: R) -> &mut Self
+    where
+        R: ToMessageId,
+    {
+        self.reply_to_message_id = Some(to.to_message_id());
+        self
+    }
+
+    pub fn disable_notification(&mut self) -> &mut Self {
+        self.disable_notification = true;
+        self
+    }
+
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<ReplyMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+}
+
+/// Reply with a video message.
+pub trait CanReplySendVideoNote {
+    fn video_note_reply<F>(&self, video_note: F) -> SendVideoNote
+    where
+        F: Into<InputFile>;
+}
+
+impl<M> CanReplySendVideoNote for M
+where
+    M: ToMessageId + ToSourceChat,
+{
+    fn video_note_reply<F>(&self, video_note: F) -> SendVideoNote
+    where
+        F: Into<InputFile>,
+    {
+        let mut req = SendVideoNote::new(self.to_source_chat(), video_note);
+        req.reply_to(self);
+        req
+    }
+}
+
+/// Send a video message.
+pub trait CanSendVideoNote {
+    fn video_note<F>(&self, video_note: F) -> SendVideoNote
+    where
+        F: Into<InputFile>;
+}
+
+impl<C> CanSendVideoNote for C
+where
+    C: ToChatRef,
+{
+    fn video_note<F>(&self, video_note: F) -> SendVideoNote
+    where
+        F: Into<InputFile>,
+    {
+        SendVideoNote::new(self.to_chat_ref(), video_note)
+    }
+}
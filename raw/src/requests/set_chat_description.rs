@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use types::*;
+use requests::*;
+
+/// Use this method to change the description of a supergroup or a channel.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatDescription<'d> {
+    chat_id: ChatRef,
+    description: Cow<'d, str>,
+}
+
+impl<'d> Request for SetChatDescription<'d> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatDescription"), self)
+    }
+}
+
+impl<'d> SetChatDescription<'d> {
+    /// Pass an empty description to remove it.
+    pub fn new<C, T>(chat: C, description: T) -> Self
+        where C: ToChatRef, T: Into<Cow<'d, str>>
+    {
+        SetChatDescription {
+            chat_id: chat.to_chat_ref(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Change the description of a supergroup or a channel.
+pub trait CanSetChatDescription {
+    fn set_description<'d, T>(&self, description: T) -> SetChatDescription<'d>
+        where T: Into<Cow<'d, str>>;
+}
+
+impl<C> CanSetChatDescription for C where C: ToChatRef {
+    fn set_description<'d, T>(&self, description: T) -> SetChatDescription<'d>
+        where T: Into<Cow<'d, str>>
+    {
+        SetChatDescription::new(self, description)
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use types::*;
+use requests::*;
+
+/// Use this method to set a new profile photo for the chat.
+/// Photos can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatPhoto {
+    chat_id: ChatRef,
+    #[serde(skip_serializing)]
+    photo: InputFileUpload,
+}
+
+impl Request for SetChatPhoto {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatPhoto"), self)
+    }
+}
+
+impl ToMultipart for SetChatPhoto {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        vec![("photo".to_string(), &self.photo)]
+    }
+}
+
+impl SetChatPhoto {
+    pub fn new<C>(chat: C, photo: InputFileUpload) -> Self where C: ToChatRef {
+        SetChatPhoto {
+            chat_id: chat.to_chat_ref(),
+            photo: photo,
+        }
+    }
+
+    /// Upload a photo from the file system.
+    pub fn with_file<C, P>(chat: C, path: P) -> Self where C: ToChatRef, P: AsRef<Path> {
+        Self::new(chat, InputFileUpload::with_path(path))
+    }
+
+    /// Upload a photo with contents in memory.
+    pub fn with_bytes<C, N>(chat: C, file_name: N, data: Vec<u8>) -> Self
+        where C: ToChatRef, N: Into<String>
+    {
+        Self::new(chat, InputFileUpload::with_bytes(file_name, data))
+    }
+}
+
+/// Set a new profile photo for the chat.
+pub trait CanSetChatPhoto {
+    fn set_photo(&self, photo: InputFileUpload) -> SetChatPhoto;
+}
+
+impl<C> CanSetChatPhoto for C where C: ToChatRef {
+    fn set_photo(&self, photo: InputFileUpload) -> SetChatPhoto {
+        SetChatPhoto::new(self, photo)
+    }
+}
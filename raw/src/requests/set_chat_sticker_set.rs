@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use types::*;
+use requests::*;
+
+/// Use this method to set a new group sticker set for a supergroup.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights. Use the field `can_set_sticker_set`
+/// returned in `getChat` requests to check if the bot can use this method.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatStickerSet<'s> {
+    chat_id: ChatRef,
+    sticker_set_name: Cow<'s, str>,
+}
+
+impl<'s> Request for SetChatStickerSet<'s> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatStickerSet"), self)
+    }
+}
+
+impl<'s> SetChatStickerSet<'s> {
+    pub fn new<C, T>(chat: C, sticker_set_name: T) -> Self
+        where C: ToChatRef, T: Into<Cow<'s, str>>
+    {
+        SetChatStickerSet {
+            chat_id: chat.to_chat_ref(),
+            sticker_set_name: sticker_set_name.into(),
+        }
+    }
+}
+
+/// Set a new group sticker set for a supergroup.
+pub trait CanSetChatStickerSet {
+    fn set_sticker_set<'s, T>(&self, sticker_set_name: T) -> SetChatStickerSet<'s>
+        where T: Into<Cow<'s, str>>;
+}
+
+impl<C> CanSetChatStickerSet for C where C: ToChatRef {
+    fn set_sticker_set<'s, T>(&self, sticker_set_name: T) -> SetChatStickerSet<'s>
+        where T: Into<Cow<'s, str>>
+    {
+        SetChatStickerSet::new(self, sticker_set_name)
+    }
+}
@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use types::*;
+use requests::*;
+
+/// Use this method to change the title of a chat. Titles can't be changed for private chats.
+/// The bot must be an administrator in the chat for this to work
+/// and must have the appropriate admin rights.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetChatTitle<'t> {
+    chat_id: ChatRef,
+    title: Cow<'t, str>,
+}
+
+impl<'t> Request for SetChatTitle<'t> {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setChatTitle"), self)
+    }
+}
+
+impl<'t> SetChatTitle<'t> {
+    pub fn new<C, T>(chat: C, title: T) -> Self where C: ToChatRef, T: Into<Cow<'t, str>> {
+        SetChatTitle {
+            chat_id: chat.to_chat_ref(),
+            title: title.into(),
+        }
+    }
+}
+
+/// Change the title of a chat.
+pub trait CanSetChatTitle {
+    fn set_title<'t, T>(&self, title: T) -> SetChatTitle<'t> where T: Into<Cow<'t, str>>;
+}
+
+impl<C> CanSetChatTitle for C where C: ToChatRef {
+    fn set_title<'t, T>(&self, title: T) -> SetChatTitle<'t> where T: Into<Cow<'t, str>> {
+        SetChatTitle::new(self, title)
+    }
+}
@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use types::*;
+use requests::*;
+
+/// Use this method to specify a url and receive incoming updates via an outgoing webhook.
+/// Whenever there is an update for the bot, Telegram will send an HTTPS POST request
+/// to the specified url, containing a JSON-serialized Update.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct SetWebhook<'u> {
+    url: Cow<'u, str>,
+    #[serde(skip_serializing)]
+    certificate: Option<InputFileUpload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_connections: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+}
+
+impl<'u> Request for SetWebhook<'u> {
+    type Type = MultipartRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        Self::Type::serialize(RequestUrl::method("setWebhook"), self)
+    }
+}
+
+impl<'u> ToMultipart for SetWebhook<'u> {
+    fn uploads(&self) -> Vec<(String, &InputFileUpload)> {
+        self.certificate.iter().map(|upload| ("certificate".to_string(), upload)).collect()
+    }
+}
+
+impl<'u> SetWebhook<'u> {
+    pub fn new<U>(url: U) -> Self where U: Into<Cow<'u, str>> {
+        SetWebhook {
+            url: url.into(),
+            certificate: None,
+            max_connections: None,
+            allowed_updates: None,
+        }
+    }
+
+    /// Upload your public key certificate so that the root certificate in use can be checked.
+    pub fn certificate(&mut self, certificate: InputFileUpload) -> &mut Self {
+        self.certificate = Some(certificate);
+        self
+    }
+
+    /// Maximum allowed number of simultaneous HTTPS connections to the webhook
+    /// for update delivery, 1-100. Defaults to 40.
+    pub fn max_connections(&mut self, max_connections: Integer) -> &mut Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    /// List the types of updates you want your bot to receive.
+    /// Specify an empty list to receive all updates regardless of type.
+    /// If not specified, the previous setting will be used.
+    pub fn allowed_updates(&mut self, updates: &[AllowedUpdate]) -> &mut Self {
+        self.allowed_updates = Some(updates.to_vec());
+        self
+    }
+}
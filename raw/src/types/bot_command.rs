@@ -0,0 +1,18 @@
+/// This object represents a bot command.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct BotCommand {
+    /// Text of the command, 1-32 characters. Can contain only lowercase English letters,
+    /// digits and underscores.
+    pub command: String,
+    /// Description of the command, 3-256 characters.
+    pub description: String,
+}
+
+impl BotCommand {
+    pub fn new<C: Into<String>, D: Into<String>>(command: C, description: D) -> Self {
+        BotCommand {
+            command: command.into(),
+            description: description.into(),
+        }
+    }
+}
@@ -161,3 +161,40 @@ pub struct RawChat {
     /// True if a group has ‘All Members Are Admins’ enabled.
     pub all_members_are_administrators: Option<bool>,
 }
+
+/// Up to date information about a chat returned by `getChat`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChatFullInfo {
+    /// The chat.
+    #[serde(flatten)]
+    pub chat: Chat,
+    /// Chat photo.
+    pub photo: Option<ChatPhoto>,
+    /// Description, for groups, supergroups and channel chats.
+    pub description: Option<String>,
+    /// Chat invite link, for groups, supergroups and channel chats.
+    pub invite_link: Option<String>,
+    /// Pinned message, for groups, supergroups and channel chats.
+    pub pinned_message: Option<Box<MessageOrChannelPost>>,
+    /// For supergroups, name of group sticker set.
+    pub sticker_set_name: Option<String>,
+    /// True, if the bot can change the group sticker set.
+    pub can_set_sticker_set: Option<bool>,
+}
+
+impl ChatFullInfo {
+    pub fn id(&self) -> ChatId {
+        self.chat.id()
+    }
+}
+
+/// This object represents a chat photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
+pub struct ChatPhoto {
+    /// Unique file identifier of small (160x160) chat photo.
+    /// This file_id can be used only for photo download.
+    pub small_file_id: String,
+    /// Unique file identifier of big (640x640) chat photo.
+    /// This file_id can be used only for photo download.
+    pub big_file_id: String,
+}
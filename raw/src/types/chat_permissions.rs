@@ -0,0 +1,80 @@
+use std::ops::Not;
+
+/// Describes actions that a non-administrator user is allowed to take in a chat.
+/// Every action is forbidden unless allowed explicitly.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct ChatPermissions {
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_send_messages: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_send_media_messages: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_send_polls: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_send_other_messages: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_add_web_page_previews: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_change_info: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_invite_users: bool,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    can_pin_messages: bool,
+}
+
+impl ChatPermissions {
+    /// Permissions which forbid everything, i.e. make the chat read-only for the user.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Allow to send text messages, contacts, locations and venues.
+    pub fn can_send_messages(&mut self) -> &mut Self {
+        self.can_send_messages = true;
+        self
+    }
+
+    /// Allow to send audios, documents, photos, videos, video notes and voice notes,
+    /// implies `can_send_messages`.
+    pub fn can_send_media_messages(&mut self) -> &mut Self {
+        self.can_send_media_messages = true;
+        self.can_send_messages()
+    }
+
+    /// Allow to send polls, implies `can_send_messages`.
+    pub fn can_send_polls(&mut self) -> &mut Self {
+        self.can_send_polls = true;
+        self.can_send_messages()
+    }
+
+    /// Allow to send animations, games, stickers and use inline bots,
+    /// implies `can_send_media_messages`.
+    pub fn can_send_other_messages(&mut self) -> &mut Self {
+        self.can_send_other_messages = true;
+        self.can_send_media_messages()
+    }
+
+    /// Allow to add web page previews to messages, implies `can_send_media_messages`.
+    pub fn can_add_web_page_previews(&mut self) -> &mut Self {
+        self.can_add_web_page_previews = true;
+        self.can_send_media_messages()
+    }
+
+    /// Allow to change the chat title, photo and other settings.
+    pub fn can_change_info(&mut self) -> &mut Self {
+        self.can_change_info = true;
+        self
+    }
+
+    /// Allow to invite new users to the chat.
+    pub fn can_invite_users(&mut self) -> &mut Self {
+        self.can_invite_users = true;
+        self
+    }
+
+    /// Allow to pin messages, supergroups only.
+    pub fn can_pin_messages(&mut self) -> &mut Self {
+        self.can_pin_messages = true;
+        self
+    }
+}
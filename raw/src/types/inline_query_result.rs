@@ -0,0 +1,1866 @@
+use std::ops::Not;
+
+use types::*;
+
+/// This object represents one result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(tag = "type")]
+pub enum InlineQueryResult {
+    #[serde(rename = "article")]
+    Article(InlineQueryResultArticle),
+    #[serde(rename = "photo")]
+    Photo(InlineQueryResultPhoto),
+    #[serde(rename = "gif")]
+    Gif(InlineQueryResultGif),
+    #[serde(rename = "mpeg4_gif")]
+    Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    #[serde(rename = "video")]
+    Video(InlineQueryResultVideo),
+    #[serde(rename = "audio")]
+    Audio(InlineQueryResultAudio),
+    #[serde(rename = "voice")]
+    Voice(InlineQueryResultVoice),
+    #[serde(rename = "document")]
+    Document(InlineQueryResultDocument),
+    #[serde(rename = "location")]
+    Location(InlineQueryResultLocation),
+    #[serde(rename = "venue")]
+    Venue(InlineQueryResultVenue),
+    #[serde(rename = "contact")]
+    Contact(InlineQueryResultContact),
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker),
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument),
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice),
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+
+impl From<InlineQueryResultArticle> for InlineQueryResult {
+    fn from(result: InlineQueryResultArticle) -> Self {
+        InlineQueryResult::Article(result)
+    }
+}
+
+impl From<InlineQueryResultPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultPhoto) -> Self {
+        InlineQueryResult::Photo(result)
+    }
+}
+
+impl From<InlineQueryResultGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultGif) -> Self {
+        InlineQueryResult::Gif(result)
+    }
+}
+
+impl From<InlineQueryResultMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultMpeg4Gif) -> Self {
+        InlineQueryResult::Mpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultVideo) -> Self {
+        InlineQueryResult::Video(result)
+    }
+}
+
+impl From<InlineQueryResultAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultAudio) -> Self {
+        InlineQueryResult::Audio(result)
+    }
+}
+
+impl From<InlineQueryResultVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultVoice) -> Self {
+        InlineQueryResult::Voice(result)
+    }
+}
+
+impl From<InlineQueryResultDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultDocument) -> Self {
+        InlineQueryResult::Document(result)
+    }
+}
+
+impl From<InlineQueryResultLocation> for InlineQueryResult {
+    fn from(result: InlineQueryResultLocation) -> Self {
+        InlineQueryResult::Location(result)
+    }
+}
+
+impl From<InlineQueryResultVenue> for InlineQueryResult {
+    fn from(result: InlineQueryResultVenue) -> Self {
+        InlineQueryResult::Venue(result)
+    }
+}
+
+impl From<InlineQueryResultContact> for InlineQueryResult {
+    fn from(result: InlineQueryResultContact) -> Self {
+        InlineQueryResult::Contact(result)
+    }
+}
+
+impl From<InlineQueryResultCachedPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedPhoto) -> Self {
+        InlineQueryResult::CachedPhoto(result)
+    }
+}
+
+impl From<InlineQueryResultCachedGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedGif) -> Self {
+        InlineQueryResult::CachedGif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedMpeg4Gif) -> Self {
+        InlineQueryResult::CachedMpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedSticker> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedSticker) -> Self {
+        InlineQueryResult::CachedSticker(result)
+    }
+}
+
+impl From<InlineQueryResultCachedDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedDocument) -> Self {
+        InlineQueryResult::CachedDocument(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVideo) -> Self {
+        InlineQueryResult::CachedVideo(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVoice) -> Self {
+        InlineQueryResult::CachedVoice(result)
+    }
+}
+
+impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedAudio) -> Self {
+        InlineQueryResult::CachedAudio(result)
+    }
+}
+
+/// Represents a link to an article or web page.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultArticle {
+    id: String,
+    title: String,
+    input_message_content: InputMessageContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Not::not")]
+    hide_url: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_height: Option<Integer>,
+}
+
+impl InlineQueryResultArticle {
+    pub fn new<I, T, IMC>(id: I, title: T, input_message_content: IMC) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        IMC: Into<InputMessageContent>,
+    {
+        InlineQueryResultArticle {
+            id: id.into(),
+            title: title.into(),
+            input_message_content: input_message_content.into(),
+            reply_markup: None,
+            url: None,
+            hide_url: false,
+            description: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// URL of the result.
+    pub fn url<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Pass `True`, if you don't want the URL to be shown in the message.
+    pub fn hide_url(&mut self) -> &mut Self {
+        self.hide_url = true;
+        self
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Url of the thumbnail for the result.
+    pub fn thumb_url<T>(&mut self, thumb_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    /// Thumbnail width.
+    pub fn thumb_width(&mut self, thumb_width: Integer) -> &mut Self {
+        self.thumb_width = Some(thumb_width);
+        self
+    }
+
+    /// Thumbnail height.
+    pub fn thumb_height(&mut self, thumb_height: Integer) -> &mut Self {
+        self.thumb_height = Some(thumb_height);
+        self
+    }
+}
+
+/// Represents a link to a photo. By default, this photo will be sent by the user
+/// with optional caption. Alternatively, you can use `input_message_content` to send
+/// a message with the specified content instead of the photo.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultPhoto {
+    id: String,
+    photo_url: String,
+    thumb_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    photo_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultPhoto {
+    pub fn new<I, PU, TU>(id: I, photo_url: PU, thumb_url: TU) -> Self
+    where
+        I: Into<String>,
+        PU: Into<String>,
+        TU: Into<String>,
+    {
+        InlineQueryResultPhoto {
+            id: id.into(),
+            photo_url: photo_url.into(),
+            thumb_url: thumb_url.into(),
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Width of the photo.
+    pub fn photo_width(&mut self, photo_width: Integer) -> &mut Self {
+        self.photo_width = Some(photo_width);
+        self
+    }
+
+    /// Height of the photo.
+    pub fn photo_height(&mut self, photo_height: Integer) -> &mut Self {
+        self.photo_height = Some(photo_height);
+        self
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to an animated GIF file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultGif {
+    id: String,
+    gif_url: String,
+    thumb_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gif_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gif_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gif_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultGif {
+    pub fn new<I, GU, TU>(id: I, gif_url: GU, thumb_url: TU) -> Self
+    where
+        I: Into<String>,
+        GU: Into<String>,
+        TU: Into<String>,
+    {
+        InlineQueryResultGif {
+            id: id.into(),
+            gif_url: gif_url.into(),
+            thumb_url: thumb_url.into(),
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Width of the GIF.
+    pub fn gif_width(&mut self, gif_width: Integer) -> &mut Self {
+        self.gif_width = Some(gif_width);
+        self
+    }
+
+    /// Height of the GIF.
+    pub fn gif_height(&mut self, gif_height: Integer) -> &mut Self {
+        self.gif_height = Some(gif_height);
+        self
+    }
+
+    /// Duration of the GIF.
+    pub fn gif_duration(&mut self, gif_duration: Integer) -> &mut Self {
+        self.gif_duration = Some(gif_duration);
+        self
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultMpeg4Gif {
+    id: String,
+    mpeg4_url: String,
+    thumb_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpeg4_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpeg4_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpeg4_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultMpeg4Gif {
+    pub fn new<I, MU, TU>(id: I, mpeg4_url: MU, thumb_url: TU) -> Self
+    where
+        I: Into<String>,
+        MU: Into<String>,
+        TU: Into<String>,
+    {
+        InlineQueryResultMpeg4Gif {
+            id: id.into(),
+            mpeg4_url: mpeg4_url.into(),
+            thumb_url: thumb_url.into(),
+            mpeg4_width: None,
+            mpeg4_height: None,
+            mpeg4_duration: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Video width.
+    pub fn mpeg4_width(&mut self, mpeg4_width: Integer) -> &mut Self {
+        self.mpeg4_width = Some(mpeg4_width);
+        self
+    }
+
+    /// Video height.
+    pub fn mpeg4_height(&mut self, mpeg4_height: Integer) -> &mut Self {
+        self.mpeg4_height = Some(mpeg4_height);
+        self
+    }
+
+    /// Video duration.
+    pub fn mpeg4_duration(&mut self, mpeg4_duration: Integer) -> &mut Self {
+        self.mpeg4_duration = Some(mpeg4_duration);
+        self
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a page containing an embedded video player or a video file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultVideo {
+    id: String,
+    video_url: String,
+    mime_type: String,
+    thumb_url: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultVideo {
+    pub fn new<I, VU, MT, TU, T>(id: I, video_url: VU, mime_type: MT, thumb_url: TU, title: T) -> Self
+    where
+        I: Into<String>,
+        VU: Into<String>,
+        MT: Into<String>,
+        TU: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultVideo {
+            id: id.into(),
+            video_url: video_url.into(),
+            mime_type: mime_type.into(),
+            thumb_url: thumb_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Video width.
+    pub fn video_width(&mut self, video_width: Integer) -> &mut Self {
+        self.video_width = Some(video_width);
+        self
+    }
+
+    /// Video height.
+    pub fn video_height(&mut self, video_height: Integer) -> &mut Self {
+        self.video_height = Some(video_height);
+        self
+    }
+
+    /// Video duration in seconds.
+    pub fn video_duration(&mut self, video_duration: Integer) -> &mut Self {
+        self.video_duration = Some(video_duration);
+        self
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to an mp3 audio file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultAudio {
+    id: String,
+    audio_url: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    performer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audio_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultAudio {
+    pub fn new<I, AU, T>(id: I, audio_url: AU, title: T) -> Self
+    where
+        I: Into<String>,
+        AU: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultAudio {
+            id: id.into(),
+            audio_url: audio_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            performer: None,
+            audio_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Performer.
+    pub fn performer<T>(&mut self, performer: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.performer = Some(performer.into());
+        self
+    }
+
+    /// Audio duration in seconds.
+    pub fn audio_duration(&mut self, audio_duration: Integer) -> &mut Self {
+        self.audio_duration = Some(audio_duration);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a voice recording in an .ogg container encoded with OPUS.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultVoice {
+    id: String,
+    voice_url: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voice_duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultVoice {
+    pub fn new<I, VU, T>(id: I, voice_url: VU, title: T) -> Self
+    where
+        I: Into<String>,
+        VU: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultVoice {
+            id: id.into(),
+            voice_url: voice_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            voice_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Recording duration in seconds.
+    pub fn voice_duration(&mut self, voice_duration: Integer) -> &mut Self {
+        self.voice_duration = Some(voice_duration);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a file. Currently, only .PDF and .ZIP files
+/// can be sent using this method.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultDocument {
+    id: String,
+    title: String,
+    document_url: String,
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_height: Option<Integer>,
+}
+
+impl InlineQueryResultDocument {
+    pub fn new<I, T, DU, MT>(id: I, title: T, document_url: DU, mime_type: MT) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        DU: Into<String>,
+        MT: Into<String>,
+    {
+        InlineQueryResultDocument {
+            id: id.into(),
+            title: title.into(),
+            document_url: document_url.into(),
+            mime_type: mime_type.into(),
+            caption: None,
+            parse_mode: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Url of the thumbnail for the result.
+    pub fn thumb_url<T>(&mut self, thumb_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    /// Thumbnail width.
+    pub fn thumb_width(&mut self, thumb_width: Integer) -> &mut Self {
+        self.thumb_width = Some(thumb_width);
+        self
+    }
+
+    /// Thumbnail height.
+    pub fn thumb_height(&mut self, thumb_height: Integer) -> &mut Self {
+        self.thumb_height = Some(thumb_height);
+        self
+    }
+}
+
+/// Represents a location on a map.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultLocation {
+    id: String,
+    latitude: Float,
+    longitude: Float,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    live_period: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_height: Option<Integer>,
+}
+
+impl InlineQueryResultLocation {
+    pub fn new<I, T>(id: I, latitude: Float, longitude: Float, title: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultLocation {
+            id: id.into(),
+            latitude: latitude,
+            longitude: longitude,
+            title: title.into(),
+            live_period: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    /// Period in seconds for which the location can be updated, should be between 60 and 86400.
+    pub fn live_period(&mut self, live_period: Integer) -> &mut Self {
+        self.live_period = Some(live_period);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Url of the thumbnail for the result.
+    pub fn thumb_url<T>(&mut self, thumb_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    /// Thumbnail width.
+    pub fn thumb_width(&mut self, thumb_width: Integer) -> &mut Self {
+        self.thumb_width = Some(thumb_width);
+        self
+    }
+
+    /// Thumbnail height.
+    pub fn thumb_height(&mut self, thumb_height: Integer) -> &mut Self {
+        self.thumb_height = Some(thumb_height);
+        self
+    }
+}
+
+/// Represents a venue.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultVenue {
+    id: String,
+    latitude: Float,
+    longitude: Float,
+    title: String,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_height: Option<Integer>,
+}
+
+impl InlineQueryResultVenue {
+    pub fn new<I, T, A>(id: I, latitude: Float, longitude: Float, title: T, address: A) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        A: Into<String>,
+    {
+        InlineQueryResultVenue {
+            id: id.into(),
+            latitude: latitude,
+            longitude: longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    /// Foursquare identifier of the venue if known.
+    pub fn foursquare_id<T>(&mut self, foursquare_id: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    /// Foursquare type of the venue, if known.
+    pub fn foursquare_type<T>(&mut self, foursquare_type: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Url of the thumbnail for the result.
+    pub fn thumb_url<T>(&mut self, thumb_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    /// Thumbnail width.
+    pub fn thumb_width(&mut self, thumb_width: Integer) -> &mut Self {
+        self.thumb_width = Some(thumb_width);
+        self
+    }
+
+    /// Thumbnail height.
+    pub fn thumb_height(&mut self, thumb_height: Integer) -> &mut Self {
+        self.thumb_height = Some(thumb_height);
+        self
+    }
+}
+
+/// Represents a contact with a phone number.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultContact {
+    id: String,
+    phone_number: String,
+    first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumb_height: Option<Integer>,
+}
+
+impl InlineQueryResultContact {
+    pub fn new<I, PN, FN>(id: I, phone_number: PN, first_name: FN) -> Self
+    where
+        I: Into<String>,
+        PN: Into<String>,
+        FN: Into<String>,
+    {
+        InlineQueryResultContact {
+            id: id.into(),
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumb_url: None,
+            thumb_width: None,
+            thumb_height: None,
+        }
+    }
+
+    /// Contact's last name.
+    pub fn last_name<T>(&mut self, last_name: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes.
+    pub fn vcard<T>(&mut self, vcard: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.vcard = Some(vcard.into());
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+
+    /// Url of the thumbnail for the result.
+    pub fn thumb_url<T>(&mut self, thumb_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    /// Thumbnail width.
+    pub fn thumb_width(&mut self, thumb_width: Integer) -> &mut Self {
+        self.thumb_width = Some(thumb_width);
+        self
+    }
+
+    /// Thumbnail height.
+    pub fn thumb_height(&mut self, thumb_height: Integer) -> &mut Self {
+        self.thumb_height = Some(thumb_height);
+        self
+    }
+}
+
+/// Represents a link to a photo stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedPhoto {
+    id: String,
+    photo_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedPhoto {
+    pub fn new<I, PFI>(id: I, photo_file_id: PFI) -> Self
+    where
+        I: Into<String>,
+        PFI: Into<String>,
+    {
+        InlineQueryResultCachedPhoto {
+            id: id.into(),
+            photo_file_id: photo_file_id.into(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to an animated GIF file stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedGif {
+    id: String,
+    gif_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedGif {
+    pub fn new<I, GFI>(id: I, gif_file_id: GFI) -> Self
+    where
+        I: Into<String>,
+        GFI: Into<String>,
+    {
+        InlineQueryResultCachedGif {
+            id: id.into(),
+            gif_file_id: gif_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a video animation (H.264/MPEG-4 AVC video without sound)
+/// stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedMpeg4Gif {
+    id: String,
+    mpeg4_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedMpeg4Gif {
+    pub fn new<I, MFI>(id: I, mpeg4_file_id: MFI) -> Self
+    where
+        I: Into<String>,
+        MFI: Into<String>,
+    {
+        InlineQueryResultCachedMpeg4Gif {
+            id: id.into(),
+            mpeg4_file_id: mpeg4_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Title of the result.
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a sticker stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedSticker {
+    id: String,
+    sticker_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedSticker {
+    pub fn new<I, SFI>(id: I, sticker_file_id: SFI) -> Self
+    where
+        I: Into<String>,
+        SFI: Into<String>,
+    {
+        InlineQueryResultCachedSticker {
+            id: id.into(),
+            sticker_file_id: sticker_file_id.into(),
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a file stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedDocument {
+    id: String,
+    title: String,
+    document_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedDocument {
+    pub fn new<I, T, DFI>(id: I, title: T, document_file_id: DFI) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        DFI: Into<String>,
+    {
+        InlineQueryResultCachedDocument {
+            id: id.into(),
+            title: title.into(),
+            document_file_id: document_file_id.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a video file stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedVideo {
+    id: String,
+    video_file_id: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedVideo {
+    pub fn new<I, VFI, T>(id: I, video_file_id: VFI, title: T) -> Self
+    where
+        I: Into<String>,
+        VFI: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultCachedVideo {
+            id: id.into(),
+            video_file_id: video_file_id.into(),
+            title: title.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Short description of the result.
+    pub fn description<T>(&mut self, description: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to a voice message stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedVoice {
+    id: String,
+    voice_file_id: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedVoice {
+    pub fn new<I, VFI, T>(id: I, voice_file_id: VFI, title: T) -> Self
+    where
+        I: Into<String>,
+        VFI: Into<String>,
+        T: Into<String>,
+    {
+        InlineQueryResultCachedVoice {
+            id: id.into(),
+            voice_file_id: voice_file_id.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
+
+/// Represents a link to an mp3 audio file stored on the Telegram servers.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InlineQueryResultCachedAudio {
+    id: String,
+    audio_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_message_content: Option<InputMessageContent>,
+}
+
+impl InlineQueryResultCachedAudio {
+    pub fn new<I, AFI>(id: I, audio_file_id: AFI) -> Self
+    where
+        I: Into<String>,
+        AFI: Into<String>,
+    {
+        InlineQueryResultCachedAudio {
+            id: id.into(),
+            audio_file_id: audio_file_id.into(),
+            caption: None,
+            parse_mode: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+
+    /// Caption of the result to be sent, 0-200 characters.
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Send Markdown or HTML, if you want Telegram apps to show bold, italic,
+    /// fixed-width text or inline URLs in the caption.
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Inline keyboard attached to the message.
+    pub fn reply_markup<R>(&mut self, reply_markup: R) -> &mut Self
+    where
+        R: Into<InlineKeyboardMarkup>,
+    {
+        self.reply_markup = Some(reply_markup.into());
+        self
+    }
+
+    /// Content of the message to be sent instead of the result.
+    pub fn input_message_content<C>(&mut self, input_message_content: C) -> &mut Self
+    where
+        C: Into<InputMessageContent>,
+    {
+        self.input_message_content = Some(input_message_content.into());
+        self
+    }
+}
@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+
+use serde::ser::{Error, Serialize, Serializer};
+
+use requests::MultipartValue;
+use types::*;
+
+/// This object represents a file to be sent. It is either a file which is already
+/// stored on the Telegram servers, an HTTP URL for Telegram to get the file from
+/// the Internet, or a new file to upload using `multipart/form-data`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum InputFile {
+    /// File which exists on the Telegram servers.
+    FileRef(FileRef),
+    /// HTTP URL of the file.
+    Url(String),
+    /// New file to upload.
+    Upload(InputFileUpload),
+}
+
+impl InputFile {
+    /// Resend a file which exists on the Telegram servers.
+    pub fn file_ref<F>(file: F) -> Self where F: ToFileRef {
+        InputFile::FileRef(file.to_file_ref())
+    }
+
+    /// Let Telegram download a file from the Internet.
+    pub fn url<T>(url: T) -> Self where T: Into<String> {
+        InputFile::Url(url.into())
+    }
+
+    /// Upload a file from the file system.
+    pub fn path<P>(path: P) -> Self where P: AsRef<Path> {
+        InputFile::Upload(InputFileUpload::with_path(path))
+    }
+
+    /// Upload a file with contents in memory.
+    pub fn bytes<N>(file_name: N, data: Vec<u8>) -> Self where N: Into<String> {
+        InputFile::Upload(InputFileUpload::with_bytes(file_name, data))
+    }
+
+    /// Returns `true` if the file should be uploaded.
+    pub fn is_upload(&self) -> bool {
+        self.upload().is_some()
+    }
+
+    /// Returns the file to upload, if any.
+    pub fn upload(&self) -> Option<&InputFileUpload> {
+        match *self {
+            InputFile::Upload(ref upload) => Some(upload),
+            _ => None,
+        }
+    }
+}
+
+impl From<FileRef> for InputFile {
+    fn from(file: FileRef) -> Self {
+        InputFile::FileRef(file)
+    }
+}
+
+impl From<InputFileUpload> for InputFile {
+    fn from(upload: InputFileUpload) -> Self {
+        InputFile::Upload(upload)
+    }
+}
+
+impl Serialize for InputFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match *self {
+            InputFile::FileRef(ref file) => file.serialize(serializer),
+            InputFile::Url(ref url) => serializer.serialize_str(url),
+            InputFile::Upload(_) => Err(S::Error::custom("file upload can't be serialized")),
+        }
+    }
+}
+
+/// New file to upload using `multipart/form-data`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct InputFileUpload {
+    file_name: Option<String>,
+    mime: Option<String>,
+    content: InputFileContent,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+enum InputFileContent {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+impl InputFileUpload {
+    /// Upload a file from the file system.
+    pub fn with_path<P>(path: P) -> Self where P: AsRef<Path> {
+        InputFileUpload {
+            file_name: None,
+            mime: None,
+            content: InputFileContent::Path(path.as_ref().to_path_buf()),
+        }
+    }
+
+    /// Upload a file with contents in memory.
+    pub fn with_bytes<N>(file_name: N, data: Vec<u8>) -> Self where N: Into<String> {
+        InputFileUpload {
+            file_name: Some(file_name.into()),
+            mime: None,
+            content: InputFileContent::Bytes(data),
+        }
+    }
+
+    /// File name reported to Telegram, defaults to the name of the uploaded file.
+    pub fn file_name<N>(&mut self, file_name: N) -> &mut Self where N: Into<String> {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// MIME type of the file, defaults to `application/octet-stream`.
+    pub fn mime<M>(&mut self, mime: M) -> &mut Self where M: Into<String> {
+        self.mime = Some(mime.into());
+        self
+    }
+
+    pub fn to_multipart_value(&self) -> MultipartValue {
+        match self.content {
+            InputFileContent::Path(ref path) => MultipartValue::Path {
+                path: path.clone(),
+                file_name: self.file_name.clone(),
+                mime: self.mime.clone(),
+            },
+            InputFileContent::Bytes(ref data) => MultipartValue::Data {
+                file_name: self.file_name.clone().unwrap_or_default(),
+                mime: self.mime.clone(),
+                data: data.clone(),
+            },
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::Not;
+
+use types::*;
+
+/// This object represents the content of a media message to be sent
+/// as a part of an album.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(tag = "type")]
+pub enum InputMedia {
+    /// Photo to be sent.
+    #[serde(rename = "photo")]
+    Photo(InputMediaPhoto),
+    /// Video to be sent.
+    #[serde(rename = "video")]
+    Video(InputMediaVideo),
+}
+
+impl InputMedia {
+    /// File to send.
+    pub fn media(&self) -> &InputFile {
+        match *self {
+            InputMedia::Photo(ref photo) => &photo.media,
+            InputMedia::Video(ref video) => &video.media,
+        }
+    }
+}
+
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(photo: InputMediaPhoto) -> Self {
+        InputMedia::Photo(photo)
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(video: InputMediaVideo) -> Self {
+        InputMedia::Video(video)
+    }
+}
+
+/// Represents a photo to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputMediaPhoto {
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    media: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+}
+
+impl InputMediaPhoto {
+    pub fn new<F>(media: F) -> Self where F: Into<InputFile> {
+        InputMediaPhoto {
+            media: media.into(),
+            caption: None,
+            parse_mode: None,
+        }
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self where T: Into<String> {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+}
+
+/// Represents a video to be sent.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputMediaVideo {
+    #[serde(skip_serializing_if = "InputFile::is_upload")]
+    media: InputFile,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<Integer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<Integer>,
+    #[serde(skip_serializing_if = "Not::not")]
+    supports_streaming: bool,
+}
+
+impl InputMediaVideo {
+    pub fn new<F>(media: F) -> Self where F: Into<InputFile> {
+        InputMediaVideo {
+            media: media.into(),
+            caption: None,
+            parse_mode: None,
+            width: None,
+            height: None,
+            duration: None,
+            supports_streaming: false,
+        }
+    }
+
+    pub fn caption<T>(&mut self, caption: T) -> &mut Self where T: Into<String> {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    /// Video width.
+    pub fn width(&mut self, width: Integer) -> &mut Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Video height.
+    pub fn height(&mut self, height: Integer) -> &mut Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Video duration in seconds.
+    pub fn duration(&mut self, duration: Integer) -> &mut Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Pass `True`, if the uploaded video is suitable for streaming.
+    pub fn supports_streaming(&mut self) -> &mut Self {
+        self.supports_streaming = true;
+        self
+    }
+}
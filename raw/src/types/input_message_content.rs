@@ -0,0 +1,184 @@
+use std::ops::Not;
+
+use types::*;
+
+/// This object represents the content of a message to be sent as a result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+pub enum InputMessageContent {
+    Text(InputTextMessageContent),
+    Location(InputLocationMessageContent),
+    Venue(InputVenueMessageContent),
+    Contact(InputContactMessageContent),
+}
+
+impl From<InputTextMessageContent> for InputMessageContent {
+    fn from(content: InputTextMessageContent) -> Self {
+        InputMessageContent::Text(content)
+    }
+}
+
+impl From<InputLocationMessageContent> for InputMessageContent {
+    fn from(content: InputLocationMessageContent) -> Self {
+        InputMessageContent::Location(content)
+    }
+}
+
+impl From<InputVenueMessageContent> for InputMessageContent {
+    fn from(content: InputVenueMessageContent) -> Self {
+        InputMessageContent::Venue(content)
+    }
+}
+
+impl From<InputContactMessageContent> for InputMessageContent {
+    fn from(content: InputContactMessageContent) -> Self {
+        InputMessageContent::Contact(content)
+    }
+}
+
+/// Represents the content of a text message to be sent as the result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputTextMessageContent {
+    message_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Not::not")]
+    disable_web_page_preview: bool,
+}
+
+impl InputTextMessageContent {
+    pub fn new<T>(message_text: T) -> Self where T: Into<String> {
+        InputTextMessageContent {
+            message_text: message_text.into(),
+            parse_mode: None,
+            disable_web_page_preview: false,
+        }
+    }
+
+    pub fn parse_mode(&mut self, parse_mode: ParseMode) -> &mut Self {
+        self.parse_mode = Some(parse_mode);
+        self
+    }
+
+    pub fn disable_preview(&mut self) -> &mut Self {
+        self.disable_web_page_preview = true;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for InputMessageContent {
+    fn from(text: &'a str) -> Self {
+        InputTextMessageContent::new(text).into()
+    }
+}
+
+impl From<String> for InputMessageContent {
+    fn from(text: String) -> Self {
+        InputTextMessageContent::new(text).into()
+    }
+}
+
+/// Represents the content of a location message to be sent as the result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputLocationMessageContent {
+    latitude: Float,
+    longitude: Float,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    live_period: Option<Integer>,
+}
+
+impl InputLocationMessageContent {
+    pub fn new(latitude: Float, longitude: Float) -> Self {
+        InputLocationMessageContent {
+            latitude: latitude,
+            longitude: longitude,
+            live_period: None,
+        }
+    }
+
+    /// Period in seconds for which the location can be updated, should be between 60 and 86400.
+    pub fn live_period(&mut self, live_period: Integer) -> &mut Self {
+        self.live_period = Some(live_period);
+        self
+    }
+}
+
+/// Represents the content of a venue message to be sent as the result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputVenueMessageContent {
+    latitude: Float,
+    longitude: Float,
+    title: String,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foursquare_type: Option<String>,
+}
+
+impl InputVenueMessageContent {
+    pub fn new<T, A>(latitude: Float, longitude: Float, title: T, address: A) -> Self
+    where
+        T: Into<String>,
+        A: Into<String>,
+    {
+        InputVenueMessageContent {
+            latitude: latitude,
+            longitude: longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+        }
+    }
+
+    /// Foursquare identifier of the venue, if known.
+    pub fn foursquare_id<T>(&mut self, foursquare_id: T) -> &mut Self where T: Into<String> {
+        self.foursquare_id = Some(foursquare_id.into());
+        self
+    }
+
+    /// Foursquare type of the venue, if known.
+    pub fn foursquare_type<T>(&mut self, foursquare_type: T) -> &mut Self where T: Into<String> {
+        self.foursquare_type = Some(foursquare_type.into());
+        self
+    }
+}
+
+/// Represents the content of a contact message to be sent as the result of an inline query.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+pub struct InputContactMessageContent {
+    phone_number: String,
+    first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcard: Option<String>,
+}
+
+impl InputContactMessageContent {
+    pub fn new<P, F>(phone_number: P, first_name: F) -> Self
+    where
+        P: Into<String>,
+        F: Into<String>,
+    {
+        InputContactMessageContent {
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+        }
+    }
+
+    /// Contact's last name.
+    pub fn last_name<T>(&mut self, last_name: T) -> &mut Self where T: Into<String> {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes.
+    pub fn vcard<T>(&mut self, vcard: T) -> &mut Self where T: Into<String> {
+        self.vcard = Some(vcard.into());
+        self
+    }
+}
@@ -1,7 +1,8 @@
 use serde::de::{Deserialize, Deserializer, Error};
 
 use types::*;
-use url::*;
+use requests::RequestUrl;
+use url::BaseUrl;
 
 /// This object represents a chat message or a channel post.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -28,6 +29,8 @@ pub struct Message {
     pub reply_to_message: Option<Box<MessageOrChannelPost>>,
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<Integer>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     /// Kind of the message.
     pub kind: MessageKind,
 }
@@ -48,6 +51,8 @@ pub struct ChannelPost {
     pub reply_to_message: Option<Box<MessageOrChannelPost>>,
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<Integer>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
     /// Kind of the message.
     pub kind: MessageKind,
 }
@@ -221,6 +226,7 @@ impl Message {
 
         let reply_to_message = raw.reply_to_message.clone();
         let edit_date = raw.edit_date;
+        let reply_markup = raw.reply_markup.clone();
 
         let forward = match (raw.forward_date,
                              &raw.forward_from,
@@ -254,6 +260,7 @@ impl Message {
                 forward: forward,
                 reply_to_message: reply_to_message,
                 edit_date: edit_date,
+                reply_markup: reply_markup,
                 kind: kind,
             })
         };
@@ -354,6 +361,7 @@ impl ChannelPost {
         };
         let reply_to_message = raw.reply_to_message.clone();
         let edit_date = raw.edit_date;
+        let reply_markup = raw.reply_markup.clone();
 
         let forward = match (raw.forward_date,
                              &raw.forward_from,
@@ -386,6 +394,7 @@ impl ChannelPost {
                 forward: forward,
                 reply_to_message: reply_to_message,
                 edit_date: edit_date,
+                reply_markup: reply_markup,
                 kind: kind,
             })
         };
@@ -583,6 +592,8 @@ pub struct RawMessage {
     /// Specified message was pinned. Note that the Message object in this field will not contain
     /// further reply_to_message fields even if it is itself a reply.
     pub pinned_message: Option<Box<MessageOrChannelPost>>,
+    /// Inline keyboard attached to the message.
+    pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
 /// This object represents one special entity in a text message.
@@ -843,8 +854,14 @@ pub struct File {
 }
 
 impl File {
+    /// Url of the file on the official Bot API server.
     pub fn get_url(&self, token: &str) -> Option<String> {
-        self.file_path.as_ref().map(|path| format!("{}file/bot{}/{}", TELEGRAM_URL, token, path))
+        self.get_url_with(&BaseUrl::default(), token)
+    }
+
+    /// Url of the file on the Bot API server located at `base_url`.
+    pub fn get_url_with(&self, base_url: &BaseUrl, token: &str) -> Option<String> {
+        self.file_path.as_ref().map(|path| RequestUrl::file(path.as_str()).url(base_url, token))
     }
 }
 
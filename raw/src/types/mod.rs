@@ -1,21 +1,37 @@
 #[macro_use]
 
 pub mod callback_query;
+pub mod bot_command;
 pub mod chat;
 pub mod chat_member;
+pub mod chat_permissions;
+pub mod inline_query;
+pub mod inline_query_result;
+pub mod input_file;
+pub mod input_media;
+pub mod input_message_content;
 pub mod message;
 pub mod primitive;
 pub mod refs;
 pub mod reply_markup;
 pub mod response_parameters;
 pub mod update;
+pub mod webhook_info;
 
+pub use self::bot_command::*;
 pub use self::callback_query::*;
 pub use self::chat::*;
 pub use self::chat_member::*;
+pub use self::chat_permissions::*;
+pub use self::inline_query::*;
+pub use self::inline_query_result::*;
+pub use self::input_file::*;
+pub use self::input_media::*;
+pub use self::input_message_content::*;
 pub use self::message::*;
 pub use self::primitive::*;
 pub use self::refs::*;
 pub use self::reply_markup::*;
 pub use self::response_parameters::*;
 pub use self::update::*;
+pub use self::webhook_info::*;
@@ -119,6 +119,12 @@ impl ToChatRef for Chat {
     }
 }
 
+impl ToChatRef for ChatFullInfo {
+    fn to_chat_ref(&self) -> ChatRef {
+        self.chat.to_chat_ref()
+    }
+}
+
 impl ToChatRef for MessageChat {
     fn to_chat_ref(&self) -> ChatRef {
         self.id().to_chat_ref()
@@ -295,6 +301,12 @@ impl<S> ToFileRef for S where S: Deref, S::Target: ToFileRef {
     }
 }
 
+impl ToFileRef for FileRef {
+    fn to_file_ref(&self) -> FileRef {
+        self.clone()
+    }
+}
+
 macro_rules! file_id_impls {
     ($name: ident) => {
         impl ToFileRef for $name {
@@ -384,3 +396,45 @@ impl Serialize for CallbackQueryId {
         serializer.serialize_str(&self.inner)
     }
 }
+
+/// Get `InlineQueryId` from the type reference.
+pub trait ToInlineQueryId {
+    fn to_inline_query_id(&self) -> InlineQueryId;
+}
+
+impl<S> ToInlineQueryId for S where S: Deref, S::Target: ToInlineQueryId {
+    fn to_inline_query_id(&self) -> InlineQueryId {
+        self.deref().to_inline_query_id()
+    }
+}
+
+impl ToInlineQueryId for InlineQuery {
+    fn to_inline_query_id(&self) -> InlineQueryId {
+        self.id.clone()
+    }
+}
+
+/// Unique identifier for InlineQuery.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InlineQueryId {
+    inner: String
+}
+
+impl<'de> ::serde::de::Deserialize<'de> for InlineQueryId {
+    fn deserialize<D>(deserializer: D) -> Result<InlineQueryId, D::Error>
+        where D: ::serde::de::Deserializer<'de>
+    {
+        let inner = ::serde::de::Deserialize::deserialize(deserializer)?;
+        Ok(Self {
+            inner
+        })
+    }
+}
+
+impl Serialize for InlineQueryId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(&self.inner)
+    }
+}
@@ -1,6 +1,9 @@
+use std::borrow::Cow;
 use std::ops::Not;
 
-use serde::ser::{Serialize, Serializer};
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Error as SerError, Serialize, Serializer};
+use serde_json::{self, Value};
 
 use types::*;
 
@@ -196,7 +199,7 @@ impl ReplyKeyboardRemove {
 }
 
 /// This object represents an inline keyboard that appears right next to the message it belongs to.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct InlineKeyboardMarkup {
     inline_keyboard: Vec<Vec<InlineKeyboardButton>>
 }
@@ -222,6 +225,11 @@ impl InlineKeyboardMarkup {
     pub fn add_empty_row(&mut self) -> &mut Vec<InlineKeyboardButton> {
         self.add_row(Default::default())
     }
+
+    /// Rows of the keyboard.
+    pub fn rows(&self) -> &[Vec<InlineKeyboardButton>] {
+        &self.inline_keyboard
+    }
 }
 
 impl From<Vec<Vec<InlineKeyboardButton>>> for InlineKeyboardMarkup {
@@ -243,6 +251,69 @@ impl InlineKeyboardButton {
             kind: InlineKeyboardButtonKind::CallbackData(callback.as_ref().to_string())
         }
     }
+
+    /// HTTP or tg:// url to be opened when button is pressed
+    pub fn url<T: AsRef<str>, U: AsRef<str>>(text: T, url: U) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::Url(url.as_ref().to_string())
+        }
+    }
+
+    /// Pressing the button will prompt the user to select one of their chats, open
+    /// that chat and insert the bot‘s username and the specified inline query in the input field.
+    /// Can be empty, in which case just the bot’s username will be inserted.
+    pub fn switch_inline_query<T: AsRef<str>, Q: AsRef<str>>(text: T, query: Q) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::SwitchInlineQuery(query.as_ref().to_string())
+        }
+    }
+
+    /// Pressing the button will insert the bot‘s username and the specified inline query
+    /// in the current chat's input field. Can be empty, in which case only the bot’s
+    /// username will be inserted.
+    pub fn switch_inline_query_current_chat<T: AsRef<str>, Q: AsRef<str>>(text: T, query: Q) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::SwitchInlineQueryCurrentChat(query.as_ref().to_string())
+        }
+    }
+
+    /// Game that will be launched when the user presses the button.
+    /// This type of button must always be the first button in the first row.
+    pub fn callback_game<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::CallbackGame(CallbackGame {})
+        }
+    }
+
+    /// Pay button. This type of button must always be the first button in the first row.
+    pub fn pay<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::Pay
+        }
+    }
+
+    /// HTTP URL used to automatically authorize the user with the Telegram Login Widget.
+    pub fn login_url<T: AsRef<str>, L: Into<LoginUrl>>(text: T, login_url: L) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            kind: InlineKeyboardButtonKind::LoginUrl(login_url.into())
+        }
+    }
+
+    /// Label text on the button.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Kind of the button.
+    pub fn kind(&self) -> &InlineKeyboardButtonKind {
+        &self.kind
+    }
 }
 
 impl Serialize for InlineKeyboardButton {
@@ -250,47 +321,164 @@ impl Serialize for InlineKeyboardButton {
         use self::InlineKeyboardButtonKind::*;
 
         let mut raw = InlineKeyboardButtonRaw {
-            text: &self.text,
+            text: Cow::Borrowed(&self.text),
             url: None,
             callback_data: None,
             switch_inline_query: None,
             switch_inline_query_current_chat: None,
-//            callback_game: None,
+            callback_game: None,
+            pay: false,
+            login_url: None,
         };
 
         match self.kind {
-//            Url(ref data) => raw.url = Some(data),
-            CallbackData(ref data) => raw.callback_data = Some(data),
-//            SwitchInlineQuery(ref data) => raw.switch_inline_query = Some(data),
-//            SwitchInlineQueryCurrentChat(ref data) => raw.switch_inline_query_current_chat = Some(data),
-//            CallbackGame(ref data) => raw.callback_game = Some(data),
+            Url(ref data) => raw.url = Some(Cow::Borrowed(data)),
+            CallbackData(ref data) => raw.callback_data = Some(Cow::Borrowed(data)),
+            SwitchInlineQuery(ref data) => raw.switch_inline_query = Some(Cow::Borrowed(data)),
+            SwitchInlineQueryCurrentChat(ref data) => {
+                raw.switch_inline_query_current_chat = Some(Cow::Borrowed(data))
+            }
+            CallbackGame(ref data) => raw.callback_game = Some(data.clone()),
+            Pay => raw.pay = true,
+            LoginUrl(ref data) => raw.login_url = Some(Cow::Borrowed(data)),
+            Unknown(ref data) => {
+                let data: Value = serde_json::from_str(data).map_err(S::Error::custom)?;
+                return Serialize::serialize(&data, serializer)
+            }
         }
 
         Serialize::serialize(&raw, serializer)
     }
 }
 
+impl<'de> Deserialize<'de> for InlineKeyboardButton {
+    fn deserialize<D>(deserializer: D) -> Result<InlineKeyboardButton, D::Error>
+        where D: Deserializer<'de>
+    {
+        use self::InlineKeyboardButtonKind::*;
+
+        let value: Value = Deserialize::deserialize(deserializer)?;
+        let raw: InlineKeyboardButtonRaw = Deserialize::deserialize(&value).map_err(D::Error::custom)?;
+
+        let kind = match raw {
+            InlineKeyboardButtonRaw { url: Some(data), .. } => Url(data.into_owned()),
+            InlineKeyboardButtonRaw { callback_data: Some(data), .. } => CallbackData(data.into_owned()),
+            InlineKeyboardButtonRaw { switch_inline_query: Some(data), .. } => {
+                SwitchInlineQuery(data.into_owned())
+            }
+            InlineKeyboardButtonRaw { switch_inline_query_current_chat: Some(data), .. } => {
+                SwitchInlineQueryCurrentChat(data.into_owned())
+            }
+            InlineKeyboardButtonRaw { callback_game: Some(data), .. } => CallbackGame(data),
+            InlineKeyboardButtonRaw { pay: true, .. } => Pay,
+            InlineKeyboardButtonRaw { login_url: Some(data), .. } => LoginUrl(data.into_owned()),
+            _ => Unknown(value.to_string()),
+        };
+
+        Ok(InlineKeyboardButton {
+            text: raw.text.into_owned(),
+            kind: kind,
+        })
+    }
+}
+
+/// Kind of the inline keyboard button.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum InlineKeyboardButtonKind {
-//    Url(String), // TODO(knsd): Url?
+    Url(String),
     CallbackData(String),  //TODO(knsd) Validate size?
-//    SwitchInlineQuery(String),
-//    SwitchInlineQueryCurrentChat(String),
-//    CallbackGame(CallbackGame),
+    SwitchInlineQuery(String),
+    SwitchInlineQueryCurrentChat(String),
+    CallbackGame(CallbackGame),
+    Pay,
+    LoginUrl(LoginUrl),
+    /// Button of a kind not supported by this library, with its JSON representation.
+    #[doc(hidden)]
+    Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 struct InlineKeyboardButtonRaw<'a> {
-    text: &'a str,
+    text: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_data: Option<Cow<'a, str>>, //TODO(knsd) Validate size?
     #[serde(skip_serializing_if = "Option::is_none")]
-    url: Option<&'a str>, // TODO(knsd): Url?
+    switch_inline_query: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    callback_data: Option<&'a str>, //TODO(knsd) Validate size?
+    switch_inline_query_current_chat: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    switch_inline_query: Option<&'a str>,
+    callback_game: Option<CallbackGame>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pay: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    switch_inline_query_current_chat: Option<&'a str>,
-//    callback_game: Option<CallbackGame>,
+    login_url: Option<Cow<'a, LoginUrl>>,
+}
+
+/// A placeholder, currently holds no information. Use BotFather to set up your game.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct CallbackGame {}
+
+/// This object represents a parameter of the inline keyboard button used to automatically
+/// authorize a user with the [Telegram Login Widget](https://core.telegram.org/widgets/login).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Serialize, Deserialize)]
+pub struct LoginUrl {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forward_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bot_username: Option<String>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    request_write_access: bool,
+}
+
+impl LoginUrl {
+    pub fn new<U: AsRef<str>>(url: U) -> Self {
+        Self {
+            url: url.as_ref().to_string(),
+            forward_text: None,
+            bot_username: None,
+            request_write_access: false,
+        }
+    }
+
+    /// An HTTP URL to be opened with user authorization data added to the query string
+    /// when the button is pressed.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// New text of the button in forwarded messages.
+    pub fn forward_text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+        self.forward_text = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Username of a bot, which will be used for user authorization.
+    /// Defaults to the username of the current bot.
+    pub fn bot_username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
+        self.bot_username = Some(username.as_ref().to_string());
+        self
+    }
+
+    /// Request the permission for your bot to send messages to the user.
+    pub fn request_write_access(&mut self) -> &mut Self {
+        self.request_write_access = true;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for LoginUrl {
+    fn from(value: &'a str) -> LoginUrl {
+        LoginUrl::new(value)
+    }
+}
+
+impl From<String> for LoginUrl {
+    fn from(value: String) -> LoginUrl {
+        LoginUrl::new(value)
+    }
 }
 
 /// Upon receiving a message with this object, Telegram clients will
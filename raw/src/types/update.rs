@@ -26,8 +26,11 @@ pub enum UpdateKind {
     ChannelPost(ChannelPost),
     /// New version of a channel post that is known to the bot and was edited
     EditedChannelPost(ChannelPost),
-    // InlineQuery(InlineQuery),
-    // ChosenInlineResult(ChosenInlineResult),
+    /// New incoming inline query
+    InlineQuery(InlineQuery),
+    /// The result of an inline query that was chosen by a user and sent to their chat partner
+    ChosenInlineResult(ChosenInlineResult),
+    /// New incoming callback query
     CallbackQuery(CallbackQuery),
     #[doc(hidden)]
     Error(String),
@@ -47,6 +50,8 @@ impl<'de> Deserialize<'de> for Update {
             EditedMessage,
             ChannelPost,
             EditedChannelPost,
+            InlineQuery,
+            ChosenInlineResult,
             CallbackQuery,
         }
 
@@ -124,6 +129,8 @@ impl<'de> Deserialize<'de> for Update {
                     (EditedMessage, edited_message);
                     (ChannelPost, channel_post);
                     (EditedChannelPost, edited_channel_post);
+                    (InlineQuery, inline_query);
+                    (ChosenInlineResult, chosen_inline_result);
                     (CallbackQuery, callback_query);
                 )
             }
@@ -133,6 +140,7 @@ impl<'de> Deserialize<'de> for Update {
             "update_id",
             "message", "edited_message",
             "channel_post", "edited_channel_post",
+            "inline_query", "chosen_inline_result",
             "callback_query",
         ];
 
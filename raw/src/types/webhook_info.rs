@@ -0,0 +1,22 @@
+use requests::AllowedUpdate;
+use types::*;
+
+/// Contains information about the current status of a webhook.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize)]
+pub struct WebhookInfo {
+    /// Webhook URL, may be empty if webhook is not set up.
+    pub url: String,
+    /// True, if a custom certificate was provided for webhook certificate checks.
+    pub has_custom_certificate: bool,
+    /// Number of updates awaiting delivery.
+    pub pending_update_count: Integer,
+    /// Unix time for the most recent error that happened when trying to deliver an update via webhook.
+    pub last_error_date: Option<Integer>,
+    /// Error message in human-readable format for the most recent error that
+    /// happened when trying to deliver an update via webhook.
+    pub last_error_message: Option<String>,
+    /// Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery.
+    pub max_connections: Option<Integer>,
+    /// A list of update types the bot is subscribed to. Defaults to all update types.
+    pub allowed_updates: Option<Vec<AllowedUpdate>>,
+}
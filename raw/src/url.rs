@@ -1 +1,72 @@
 pub const TELEGRAM_URL: &'static str = "https://api.telegram.org/";
+
+/// Base URL of the Bot API server, the official server is used by default.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct BaseUrl {
+    url: String,
+    test_environment: bool,
+}
+
+impl BaseUrl {
+    /// Use a self-hosted Bot API server or a mock server located at `url`.
+    pub fn new<U>(url: U) -> Self where U: Into<String> {
+        let mut url = url.into();
+        if !url.ends_with('/') {
+            url.push('/');
+        }
+
+        BaseUrl {
+            url: url,
+            test_environment: false,
+        }
+    }
+
+    /// Use the Telegram test environment of the server.
+    pub fn test_environment(self) -> Self {
+        BaseUrl {
+            test_environment: true,
+            ..self
+        }
+    }
+
+    pub fn is_test_environment(&self) -> bool {
+        self.test_environment
+    }
+
+    /// Returns `true` for the official server located at `TELEGRAM_URL`.
+    pub fn is_official(&self) -> bool {
+        self.url == TELEGRAM_URL
+    }
+
+    /// Url of the Bot API `method`.
+    pub fn method_url(&self, token: &str, method: &str) -> String {
+        format!("{}bot{}/{}{}", self.url, token, self.environment(), method)
+    }
+
+    /// Url of the file returned by the `getFile` method.
+    pub fn file_url(&self, token: &str, file_path: &str) -> String {
+        format!("{}file/bot{}/{}{}", self.url, token, self.environment(), file_path)
+    }
+
+    fn environment(&self) -> &'static str {
+        if self.test_environment { "test/" } else { "" }
+    }
+}
+
+impl Default for BaseUrl {
+    fn default() -> Self {
+        BaseUrl::new(TELEGRAM_URL)
+    }
+}
+
+impl<'a> From<&'a str> for BaseUrl {
+    fn from(url: &'a str) -> Self {
+        BaseUrl::new(url)
+    }
+}
+
+impl From<String> for BaseUrl {
+    fn from(url: String) -> Self {
+        BaseUrl::new(url)
+    }
+}
@@ -0,0 +1,75 @@
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use telegram_bot_raw::{Body, Chat, ChatFullInfo, ChatPhoto, MessageOrChannelPost, MultipartValue, Request};
+use telegram_bot_raw::{ChatId, GetMe, SendMessage, SetChatPhoto, SupergroupId, ToChatRef};
+
+#[test]
+fn get_chat_supergroup() {
+    let json = r#"{
+        "id": -1001234567890,
+        "type": "supergroup",
+        "title": "Group",
+        "description": "Description",
+        "invite_link": "https://t.me/joinchat/AAAAAAAAAAAAAAAAAAAAAA",
+        "photo": {"small_file_id": "small", "big_file_id": "big"},
+        "sticker_set_name": "stickers",
+        "can_set_sticker_set": true,
+        "pinned_message": {
+            "message_id": 1,
+            "from": {"id": 1, "first_name": "User"},
+            "chat": {"id": -1001234567890, "type": "supergroup", "title": "Group"},
+            "date": 1500000000,
+            "text": "pinned"
+        }
+    }"#;
+
+    let info: ChatFullInfo = serde_json::from_str(json).unwrap();
+    match info.chat {
+        Chat::Supergroup(ref group) => assert_eq!(group.title, "Group"),
+        ref chat => panic!("unexpected chat {:?}", chat),
+    }
+    assert_eq!(info.id(), ChatId::new(-1001234567890));
+
+    assert_eq!(info.description, Some("Description".to_string()));
+    assert_eq!(info.invite_link, Some("https://t.me/joinchat/AAAAAAAAAAAAAAAAAAAAAA".to_string()));
+    assert_eq!(info.photo, Some(ChatPhoto {
+        small_file_id: "small".to_string(),
+        big_file_id: "big".to_string(),
+    }));
+    assert_eq!(info.sticker_set_name, Some("stickers".to_string()));
+    assert_eq!(info.can_set_sticker_set, Some(true));
+    match info.pinned_message.map(|message| *message) {
+        Some(MessageOrChannelPost::Message(ref message)) => assert_eq!(message.id, 1.into()),
+        message => panic!("unexpected pinned message {:?}", message),
+    }
+}
+
+#[test]
+fn set_chat_photo_is_multipart() {
+    let request = SetChatPhoto::with_bytes(SupergroupId::new(-100), "photo.jpg", vec![1, 2, 3]);
+
+    let parts = match request.serialize().unwrap().body {
+        Body::Multipart(parts) => parts,
+        body => panic!("unexpected body {:?}", body),
+    };
+
+    assert!(parts.contains(&("chat_id".to_string(), MultipartValue::Text("-100".to_string()))));
+    assert!(parts.contains(&("photo".to_string(), MultipartValue::Data {
+        file_name: "photo.jpg".to_string(),
+        mime: None,
+        data: vec![1, 2, 3],
+    })));
+}
+
+#[test]
+fn request_chat_ref() {
+    let json = SendMessage::new(ChatId::new(1), "text").serialize().unwrap();
+    assert_eq!(json.chat_ref(), Some(ChatId::new(1).to_chat_ref()));
+
+    let multipart = SetChatPhoto::with_bytes(SupergroupId::new(-100), "photo.jpg", vec![]);
+    let multipart = multipart.serialize().unwrap();
+    assert_eq!(multipart.chat_ref(), Some(SupergroupId::new(-100).to_chat_ref()));
+
+    assert_eq!(GetMe.serialize().unwrap().chat_ref(), None);
+}
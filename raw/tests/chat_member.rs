@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use serde_json::Value;
+use telegram_bot_raw::{Body, ChatPermissions, Request, SupergroupId, UserId};
+use telegram_bot_raw::{CanPromoteChatMemberForUser, CanRestrictChatMemberForChat};
+
+fn json_body<R: Request>(request: R) -> Value {
+    match request.serialize().unwrap().body {
+        Body::Json(json) => serde_json::from_slice(&json).unwrap(),
+        body => panic!("unexpected body {:?}", body),
+    }
+}
+
+#[test]
+fn restrict_chat_member() {
+    let mut permissions = ChatPermissions::new();
+    permissions.can_send_other_messages().can_invite_users();
+
+    let mut request = SupergroupId::new(-100).restrict(UserId::new(1), permissions);
+    request.until_date(1500000000);
+
+    assert_eq!(json_body(request), json!({
+        "chat_id": -100,
+        "user_id": 1,
+        "permissions": {
+            "can_send_messages": true,
+            "can_send_media_messages": true,
+            "can_send_other_messages": true,
+            "can_invite_users": true,
+        },
+        "until_date": 1500000000,
+    }));
+}
+
+#[test]
+fn restrict_chat_member_read_only() {
+    let request = SupergroupId::new(-100).restrict(UserId::new(1), ChatPermissions::new());
+    assert_eq!(json_body(request), json!({
+        "chat_id": -100,
+        "user_id": 1,
+        "permissions": {},
+    }));
+}
+
+#[test]
+fn promote_chat_member() {
+    let mut request = UserId::new(1).promote_in(SupergroupId::new(-100));
+    request.can_pin_messages().can_delete_messages();
+
+    assert_eq!(json_body(request), json!({
+        "chat_id": -100,
+        "user_id": 1,
+        "can_delete_messages": true,
+        "can_pin_messages": true,
+    }));
+}
@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use serde_json::Value;
+use telegram_bot_raw::{AllowedUpdate, Body, ErrorKind, GetUpdates, HttpResponse, Request, ResponseType};
+
+fn json_body<R: Request>(request: R) -> Value {
+    match request.serialize().unwrap().body {
+        Body::Json(json) => serde_json::from_slice(&json).unwrap(),
+        body => panic!("unexpected body {:?}", body),
+    }
+}
+
+#[test]
+fn get_updates_default() {
+    assert_eq!(json_body(GetUpdates::new()), json!({}));
+}
+
+#[test]
+fn get_updates_allowed_updates() {
+    let mut request = GetUpdates::new();
+    request.offset(10).allowed_updates(&[AllowedUpdate::Message]);
+
+    assert_eq!(json_body(request), json!({
+        "offset": 10,
+        "allowed_updates": ["message"],
+    }));
+}
+
+#[test]
+fn get_updates_error_code() {
+    let body = json!({"ok": false, "error_code": 409, "description": "Conflict: terminated by other getUpdates request"});
+    let response = HttpResponse { body: Some(serde_json::to_vec(&body).unwrap()) };
+
+    let error = <GetUpdates as Request>::Response::deserialize(response).unwrap_err();
+    match *error.kind() {
+        ErrorKind::TelegramError { error_code, .. } => assert_eq!(error_code, Some(409)),
+        ref kind => panic!("unexpected error {:?}", kind),
+    }
+}
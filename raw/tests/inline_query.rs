@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use telegram_bot_raw::{Body, InlineQuery, Request};
+use telegram_bot_raw::CanAnswerInlineQuery;
+use telegram_bot_raw::{InlineQueryResultArticle, InlineQueryResultCachedSticker};
+
+#[test]
+fn answer_inline_query() {
+    let query: InlineQuery = serde_json::from_value(json!({
+        "id": "42",
+        "from": {"id": 1, "first_name": "Test"},
+        "query": "query",
+        "offset": "",
+    })).unwrap();
+
+    let mut article = InlineQueryResultArticle::new("1", "Title", "Text");
+    article.description("Description");
+
+    let mut request = query.answer(vec![article]);
+    request.add_result(InlineQueryResultCachedSticker::new("2", "sticker_file_id"));
+    request.cache_time(0).is_personal();
+
+    let body = match request.serialize().unwrap().body {
+        Body::Json(body) => serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+        body => panic!("unexpected body {:?}", body),
+    };
+
+    assert_eq!(body, json!({
+        "inline_query_id": "42",
+        "results": [
+            {
+                "type": "article",
+                "id": "1",
+                "title": "Title",
+                "input_message_content": {"message_text": "Text"},
+                "description": "Description",
+            },
+            {
+                "type": "sticker",
+                "id": "2",
+                "sticker_file_id": "sticker_file_id",
+            },
+        ],
+        "cache_time": 0,
+        "is_personal": true,
+    }));
+}
@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use telegram_bot_raw::{Body, ChatId, Error, ErrorKind, FileRef, MultipartValue, Request};
+use telegram_bot_raw::{InputFile, InputMediaPhoto, InputMediaVideo};
+use telegram_bot_raw::{SendAudio, SendMediaGroup, SendPhoto, SendVideo};
+
+#[test]
+fn send_audio_url_is_json() {
+    let request = SendAudio::with_url(ChatId::new(1), "https://example.com/audio.mp3");
+    match request.serialize().unwrap().body {
+        Body::Json(_) => (),
+        body => panic!("unexpected body {:?}", body),
+    }
+}
+
+#[test]
+fn send_audio_bytes_is_multipart() {
+    let mut request = SendAudio::with_bytes(ChatId::new(1), "audio.mp3", vec![1, 2, 3]);
+    request.title("Title");
+
+    let parts = match request.serialize().unwrap().body {
+        Body::Multipart(parts) => parts,
+        body => panic!("unexpected body {:?}", body),
+    };
+
+    assert!(parts.contains(&("chat_id".to_string(), MultipartValue::Text("1".to_string()))));
+    assert!(parts.contains(&("title".to_string(), MultipartValue::Text("Title".to_string()))));
+    assert!(parts.contains(&("audio".to_string(), MultipartValue::Data {
+        file_name: "audio.mp3".to_string(),
+        mime: None,
+        data: vec![1, 2, 3],
+    })));
+}
+
+#[test]
+fn send_photo_file_ref_is_json() {
+    let mut request = SendPhoto::new(ChatId::new(1), FileRef::from("file_id"));
+    request.caption("Caption");
+
+    match request.serialize().unwrap().body {
+        Body::Json(json) => {
+            let json = String::from_utf8(json).unwrap();
+            assert!(json.contains(r#""photo":"file_id""#));
+            assert!(json.contains(r#""caption":"Caption""#));
+        }
+        body => panic!("unexpected body {:?}", body),
+    }
+}
+
+#[test]
+fn send_video_path_is_multipart() {
+    let mut request = SendVideo::with_file(ChatId::new(1), "/tmp/video.mp4");
+    request.supports_streaming();
+
+    let parts = match request.serialize().unwrap().body {
+        Body::Multipart(parts) => parts,
+        body => panic!("unexpected body {:?}", body),
+    };
+
+    assert!(parts.contains(&("supports_streaming".to_string(), MultipartValue::Text("true".to_string()))));
+    assert!(parts.contains(&("video".to_string(), MultipartValue::Path {
+        path: "/tmp/video.mp4".into(),
+        file_name: None,
+        mime: None,
+    })));
+}
+
+#[test]
+fn send_media_group_attaches_uploads() {
+    let mut request = SendMediaGroup::new(ChatId::new(1), vec![
+        InputMediaPhoto::new(FileRef::from("file_id")),
+    ]);
+    request.add_media(InputMediaVideo::new(InputFile::bytes("video.mp4", vec![1, 2, 3])));
+
+    let parts = match request.serialize().unwrap().body {
+        Body::Multipart(parts) => parts,
+        body => panic!("unexpected body {:?}", body),
+    };
+
+    let media = match parts.iter().find(|&&(ref name, _)| name == "media") {
+        Some(&(_, MultipartValue::Text(ref media))) => serde_json::from_str::<serde_json::Value>(media).unwrap(),
+        part => panic!("unexpected part {:?}", part),
+    };
+    assert_eq!(media, json!([
+        {"type": "photo", "media": "file_id"},
+        {"type": "video", "media": "attach://media1"},
+    ]));
+    assert!(parts.contains(&("media1".to_string(), MultipartValue::Data {
+        file_name: "video.mp4".to_string(),
+        mime: None,
+        data: vec![1, 2, 3],
+    })));
+}
+
+#[test]
+fn send_media_group_checks_size() {
+    let request = SendMediaGroup::new(ChatId::new(1), vec![
+        InputMediaPhoto::new(FileRef::from("file_id")),
+    ]);
+
+    match request.serialize() {
+        Err(Error(ErrorKind::InvalidMediaGroupSize(1), _)) => (),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
@@ -8,6 +8,7 @@ use std::fs::File;
 
 use telegram_bot_raw::types::update::{Update, UpdateKind};
 use telegram_bot_raw::types::message::MessageKind;
+use telegram_bot_raw::types::reply_markup::InlineKeyboardButtonKind;
 
 macro_rules! make_test {
     ($asset: ident, $test: expr) => {
@@ -43,3 +44,39 @@ make_test!(migrate_to_chat_id, |update: Update| {
     }
     assert!(false)
 });
+
+make_test!(inline_query, |update: Update| {
+    if let UpdateKind::InlineQuery(query) = update.kind {
+        assert_eq!(query.query, "inline query");
+        assert!(query.location.is_some());
+        return ()
+    }
+    assert!(false)
+});
+
+make_test!(chosen_inline_result, |update: Update| {
+    if let UpdateKind::ChosenInlineResult(result) = update.kind {
+        assert_eq!(result.result_id, "12");
+        assert_eq!(result.inline_message_id, Some("1234csdbsk4839".to_string()));
+        return ()
+    }
+    assert!(false)
+});
+
+make_test!(inline_keyboard, |update: Update| {
+    if let UpdateKind::EditedMessage(message) = update.kind {
+        let markup = message.reply_markup.unwrap();
+        let kinds: Vec<_> = markup.rows().iter().flat_map(|row| row.iter().map(|button| button.kind().clone())).collect();
+        assert_eq!(kinds, vec![
+            InlineKeyboardButtonKind::CallbackData("data".to_string()),
+            InlineKeyboardButtonKind::Url("https://example.com".to_string()),
+            InlineKeyboardButtonKind::SwitchInlineQuery("query".to_string()),
+            InlineKeyboardButtonKind::Pay,
+            InlineKeyboardButtonKind::Unknown(
+                r#"{"text":"App","web_app":{"url":"https://example.com/app"}}"#.to_string()
+            ),
+        ]);
+        return ()
+    }
+    assert!(false)
+});
@@ -0,0 +1,30 @@
+extern crate telegram_bot_raw;
+
+use telegram_bot_raw::{BaseUrl, File, RequestUrl};
+
+#[test]
+fn default_url() {
+    let url = RequestUrl::method("getMe").url(&BaseUrl::default(), "token");
+    assert_eq!(url, "https://api.telegram.org/bottoken/getMe");
+}
+
+#[test]
+fn custom_url() {
+    let base_url = BaseUrl::new("http://localhost:8081");
+    assert_eq!(RequestUrl::method("getMe").url(&base_url, "token"), "http://localhost:8081/bottoken/getMe");
+}
+
+#[test]
+fn test_environment_url() {
+    let base_url = BaseUrl::default().test_environment();
+    assert_eq!(RequestUrl::method("getMe").url(&base_url, "token"),
+               "https://api.telegram.org/bottoken/test/getMe");
+
+    let file = File {
+        file_id: "file_id".to_string(),
+        file_size: None,
+        file_path: Some("photos/file.jpg".to_string()),
+    };
+    assert_eq!(file.get_url_with(&base_url, "token"),
+               Some("https://api.telegram.org/file/bottoken/test/photos/file.jpg".to_string()));
+}
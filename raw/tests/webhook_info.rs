@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate serde_json;
+extern crate telegram_bot_raw;
+
+use serde_json::Value;
+use telegram_bot_raw::{AllowedUpdate, Body, Request, SetWebhook, WebhookInfo};
+
+#[test]
+fn webhook_info() {
+    let info: WebhookInfo = serde_json::from_value(json!({
+        "url": "https://example.com/hook",
+        "has_custom_certificate": false,
+        "pending_update_count": 2,
+        "last_error_date": 1441645532,
+        "last_error_message": "Connection refused",
+        "max_connections": 40,
+        "allowed_updates": ["message", "callback_query", "chat_member"],
+    })).unwrap();
+
+    assert_eq!(info.url, "https://example.com/hook");
+    assert_eq!(info.pending_update_count, 2);
+    assert_eq!(info.last_error_message, Some("Connection refused".to_string()));
+    assert_eq!(info.allowed_updates, Some(vec![
+        AllowedUpdate::Message,
+        AllowedUpdate::CallbackQuery,
+        AllowedUpdate::Unknown("chat_member".to_string()),
+    ]));
+}
+
+#[test]
+fn webhook_info_not_set() {
+    let info: WebhookInfo = serde_json::from_value(json!({
+        "url": "",
+        "has_custom_certificate": false,
+        "pending_update_count": 0,
+    })).unwrap();
+
+    assert_eq!(info.url, "");
+    assert_eq!(info.allowed_updates, None);
+}
+
+#[test]
+fn set_webhook_allowed_updates() {
+    let mut request = SetWebhook::new("https://example.com/hook");
+    request.allowed_updates(&[AllowedUpdate::Message, AllowedUpdate::Unknown("chat_member".to_string())]);
+
+    let body: Value = match request.serialize().unwrap().body {
+        Body::Json(json) => serde_json::from_slice(&json).unwrap(),
+        body => panic!("unexpected body {:?}", body),
+    };
+    assert_eq!(body, json!({
+        "url": "https://example.com/hook",
+        "allowed_updates": ["message", "chat_member"],
+    }));
+}